@@ -0,0 +1,699 @@
+use common::parse::parse;
+use std::{cmp::Reverse, collections::BinaryHeap, fmt, ops::RangeInclusive};
+
+#[cfg(feature = "embed-input")]
+pub const INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embed-input"))]
+pub const INPUT: Option<&str> = None;
+
+// One block of calories per elf, separated by a blank line. Every further
+// blank line between two blocks is an elf carrying nothing, but blank lines
+// before the first elf and after the last are ignored, so a blank input has
+// no elves. Lines may end in CRLF, and a line of only spaces or tabs counts
+// as blank.
+//
+// Calls `elf` with each elf's items in turn, so the elves are never all held
+// at once.
+pub fn try_for_each_elf(string: &str, mut elf: impl FnMut(&[u64])) -> common::parse::Result<()> {
+    parse(string, |parser| {
+        let mut items = Vec::new();
+        // None until the first elf
+        let mut blank_lines = None;
+        while !parser.at_end() {
+            if parser.at_blank_line() {
+                parser.blank_line()?;
+                if let Some(blank_lines) = &mut blank_lines {
+                    *blank_lines += 1;
+                }
+                continue;
+            }
+            for _ in 1..blank_lines.unwrap_or(1) {
+                elf(&[]);
+            }
+            items.clear();
+            while !parser.at_blank_line() {
+                items.push(parser.number()?);
+                parser.line_end()?;
+            }
+            elf(&items);
+            blank_lines = Some(0);
+        }
+        Ok(())
+    })
+}
+
+pub fn for_each_elf(string: &str, elf: impl FnMut(&[u64])) {
+    try_for_each_elf(string, elf).unwrap_or_else(|error| panic!("{}", error))
+}
+
+pub fn try_split_input(string: &str) -> common::parse::Result<Vec<Vec<u64>>> {
+    let mut elves = Vec::new();
+    try_for_each_elf(string, |items| elves.push(items.to_vec()))?;
+    Ok(elves)
+}
+
+pub fn split_input(string: &str) -> Vec<Vec<u64>> {
+    try_split_input(string).unwrap_or_else(|error| panic!("{}", error))
+}
+
+// The `k` largest of the items pushed into it. A min-heap of at most `k`
+// items keeps the largest seen so far while the rest stream past, so this
+// takes O(n log k) rather than sorting all n.
+struct Largest<T> {
+    heap: BinaryHeap<Reverse<T>>,
+    k: usize,
+}
+
+impl<T: Ord> Largest<T> {
+    fn new(k: usize) -> Largest<T> {
+        Largest {
+            heap: BinaryHeap::with_capacity(k + 1),
+            k,
+        }
+    }
+
+    // The item that no longer fits, if any
+    fn push(&mut self, item: T) -> Option<T> {
+        self.heap.push(Reverse(item));
+        if self.heap.len() > self.k {
+            self.heap.pop().map(|Reverse(item)| item)
+        } else {
+            None
+        }
+    }
+
+    fn smallest(&self) -> Option<&T> {
+        self.heap.peek().map(|Reverse(item)| item)
+    }
+
+    // Largest first
+    fn into_vec(self) -> Vec<T> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(item)| item)
+            .collect()
+    }
+}
+
+// The calorie totals of the `k` elves carrying the most, most first
+pub fn top_k(input: &str, k: usize) -> Vec<u64> {
+    let mut largest = Largest::new(k);
+    for_each_elf(input, |items| {
+        largest.push(items.iter().sum());
+    });
+    largest.into_vec()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elf {
+    // Counted from 1 in the order of the input
+    pub number: usize,
+    // 1 plus how many elves carry more, so elves carrying as much share it
+    pub rank: usize,
+    pub calories: u64,
+    pub items: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ranking {
+    // The `k` elves carrying the most, most first. Of elves carrying as much,
+    // the first in the input comes first.
+    pub top: Vec<Elf>,
+    // The elves that carry as much as the last of `top` but didn't fit in
+    pub tied_out: Vec<Elf>,
+}
+
+pub fn top_elves(input: &str, k: usize) -> Ranking {
+    // Of elves carrying as much, the later one is smaller and is dropped
+    // first
+    let mut largest = Largest::new(k);
+    // Dropped elves carrying as much as the smallest one kept
+    let mut dropped = Vec::new();
+    let mut number = 0;
+    for_each_elf(input, |items| {
+        number += 1;
+        let elf = (items.iter().sum::<u64>(), Reverse(number), items.len());
+        if let (Some(out), Some(&(smallest, _, _))) = (largest.push(elf), largest.smallest()) {
+            dropped.retain(|&(calories, _, _)| calories == smallest);
+            if out.0 == smallest {
+                dropped.push(out);
+            }
+        }
+    });
+
+    let mut top: Vec<Elf> = Vec::new();
+    for (calories, Reverse(number), items) in largest.into_vec() {
+        let rank = match top.last() {
+            Some(previous) if previous.calories == calories => previous.rank,
+            _ => top.len() + 1,
+        };
+        top.push(Elf {
+            number,
+            rank,
+            calories,
+            items,
+        });
+    }
+
+    dropped.sort_by_key(|&(_, Reverse(number), _)| number);
+    let tied_out = match top.last() {
+        Some(&last) => dropped
+            .into_iter()
+            .filter(|&(calories, Reverse(number), _)| {
+                calories == last.calories && number > last.number
+            })
+            .map(|(calories, Reverse(number), items)| Elf {
+                number,
+                calories,
+                items,
+                ..last
+            })
+            .collect(),
+        None => Vec::new(),
+    };
+    Ranking { top, tied_out }
+}
+
+pub const TOP_ELVES: usize = 3;
+
+pub fn part_1(input: &str) -> u64 {
+    top_k(input, 1).iter().sum()
+}
+
+pub fn part_2(input: &str) -> u64 {
+    top_k(input, TOP_ELVES).iter().sum()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statistics {
+    // Of the calorie totals
+    pub elves: usize,
+    pub mean: f64,
+    pub median: f64,
+    pub percentiles: Vec<(u8, u64)>,
+    // Equally wide ranges of totals, with how many elves fall in each
+    pub histogram: Vec<(RangeInclusive<u64>, usize)>,
+    // The calories of the largest single item and the elf carrying it
+    pub largest_item: Option<(u64, usize)>,
+    // The numbers of the elves carrying nothing
+    pub empty_elves: Vec<usize>,
+}
+
+pub const PERCENTILES: [u8; 4] = [10, 25, 75, 90];
+pub const HISTOGRAM_BUCKETS: u64 = 10;
+
+// Nearest-rank percentile of sorted values
+fn percentile(sorted: &[u64], percent: u8) -> u64 {
+    let rank = (usize::from(percent) * sorted.len()).div_ceil(100);
+    sorted[rank.max(1) - 1]
+}
+
+// None when there are no elves to describe
+pub fn statistics(input: &str) -> Option<Statistics> {
+    let elves = split_input(input);
+    let mut totals = elves
+        .iter()
+        .map(|set| set.iter().sum::<u64>())
+        .collect::<Vec<_>>();
+    totals.sort_unstable();
+    let count = totals.len();
+    let (&lowest, &highest) = (totals.first()?, totals.last()?);
+
+    let median = if count % 2 == 0 {
+        (totals[count / 2 - 1] as f64 + totals[count / 2] as f64) / 2.0
+    } else {
+        totals[count / 2] as f64
+    };
+
+    let width = (highest - lowest).div_ceil(HISTOGRAM_BUCKETS).max(1);
+    let histogram = (0..HISTOGRAM_BUCKETS)
+        .map(|bucket| (bucket, lowest + bucket * width))
+        .take_while(|&(_, start)| start <= highest)
+        .map(|(bucket, start)| {
+            // The last bucket takes the highest total too
+            let end = if bucket == HISTOGRAM_BUCKETS - 1 || start + width > highest {
+                highest
+            } else {
+                start + width - 1
+            };
+            let range = start..=end;
+            let elves = totals.iter().filter(|total| range.contains(total)).count();
+            (range, elves)
+        })
+        .collect();
+
+    Some(Statistics {
+        elves: count,
+        mean: totals.iter().map(|&total| total as f64).sum::<f64>() / count as f64,
+        median,
+        percentiles: PERCENTILES
+            .iter()
+            .map(|&percent| (percent, percentile(&totals, percent)))
+            .collect(),
+        histogram,
+        largest_item: elves
+            .iter()
+            .enumerate()
+            .flat_map(|(index, set)| set.iter().map(move |&item| (item, Reverse(index + 1))))
+            .max()
+            .map(|(item, Reverse(number))| (item, number)),
+        empty_elves: elves
+            .iter()
+            .enumerate()
+            .filter(|(_, set)| set.is_empty())
+            .map(|(index, _)| index + 1)
+            .collect(),
+    })
+}
+
+impl fmt::Display for Statistics {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(formatter, "Elves: {}", self.elves)?;
+        writeln!(
+            formatter,
+            "Calories: mean {:.1}, median {:.1}",
+            self.mean, self.median
+        )?;
+        let percentiles = self
+            .percentiles
+            .iter()
+            .map(|(percent, total)| format!("p{} {}", percent, total))
+            .collect::<Vec<_>>();
+        writeln!(formatter, "Percentiles: {}", percentiles.join(", "))?;
+        if let Some((item, number)) = self.largest_item {
+            writeln!(
+                formatter,
+                "Largest item: {} calories, carried by elf {}",
+                item, number
+            )?;
+        }
+        let empty_elves = self
+            .empty_elves
+            .iter()
+            .map(|number| number.to_string())
+            .collect::<Vec<_>>();
+        match empty_elves.len() {
+            0 => writeln!(formatter, "Elves carrying nothing: none")?,
+            _ => writeln!(
+                formatter,
+                "Elves carrying nothing: {}",
+                empty_elves.join(", ")
+            )?,
+        }
+
+        // The fullest bucket gets a bar of 40
+        let fullest = self
+            .histogram
+            .iter()
+            .map(|(_, elves)| *elves)
+            .max()
+            .unwrap_or(0);
+        writeln!(formatter, "Histogram:")?;
+        for (range, elves) in &self.histogram {
+            writeln!(
+                formatter,
+                "{:>8} - {:>8} | {} {}",
+                range.start(),
+                range.end(),
+                "#".repeat((elves * 40).div_ceil(fullest.max(1))),
+                elves
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// Every item handed out again so that the heaviest load is as light as
+// possible, the multiway number partitioning problem
+#[derive(Debug, Clone, PartialEq)]
+pub struct Plan {
+    // The items of each elf, heaviest first
+    pub elves: Vec<Vec<u64>>,
+    // Whether no plan can have a lighter heaviest load
+    pub optimal: bool,
+    // The heaviest load is at least the largest item and at least the share
+    // of an elf if everything could be split evenly
+    pub lower_bound: u64,
+}
+
+// How many partial plans the exact search looks at before settling for the
+// best plan it found. The search grows with both the items and the elves, so
+// this rather than either bounds how long planning takes.
+pub const PLAN_SEARCH_NODES: usize = 100_000;
+
+impl Plan {
+    pub fn loads(&self) -> Vec<u64> {
+        self.elves.iter().map(|items| items.iter().sum()).collect()
+    }
+
+    pub fn heaviest(&self) -> u64 {
+        self.loads().into_iter().max().unwrap_or(0)
+    }
+
+    // How many times the best possible heaviest load this one is at most.
+    // Longest processing time first is at most 4/3 - 1/(3 * elves) times it
+    // (Graham, 1969), and the lower bound often says it's closer.
+    pub fn worst_ratio(&self) -> f64 {
+        if self.optimal || self.lower_bound == 0 {
+            return 1.0;
+        }
+        let elves = self.elves.len() as f64;
+        let graham = 4.0 / 3.0 - 1.0 / (3.0 * elves);
+        graham.min(self.heaviest() as f64 / self.lower_bound as f64)
+    }
+}
+
+// Longest processing time first: each item, largest first, goes to the elf
+// carrying the least so far. Takes the items sorted largest first and returns
+// the elf of each item.
+fn longest_first(items: &[u64], elves: usize) -> Vec<usize> {
+    let mut loads = (0..elves)
+        .map(|elf| Reverse((0, elf)))
+        .collect::<BinaryHeap<_>>();
+    items
+        .iter()
+        .map(|&item| {
+            let Reverse((load, elf)) = loads.pop().unwrap();
+            loads.push(Reverse((load + item, elf)));
+            elf
+        })
+        .collect()
+}
+
+struct Search<'a> {
+    items: &'a [u64],
+    lower_bound: u64,
+    loads: Vec<u64>,
+    assignment: Vec<usize>,
+    best: (u64, Vec<usize>),
+    // Partial plans left to look at
+    nodes: usize,
+}
+
+impl Search<'_> {
+    // Tries every elf for each item, largest first, leaving out elves that
+    // would carry as much as the best plan so far
+    fn assign(&mut self, index: usize) {
+        if self.best.0 == self.lower_bound || self.nodes == 0 {
+            return;
+        }
+        self.nodes -= 1;
+        let Some(&item) = self.items.get(index) else {
+            self.best = (
+                self.loads.iter().copied().max().unwrap(),
+                self.assignment.clone(),
+            );
+            return;
+        };
+        for elf in 0..self.loads.len() {
+            // Elves carrying as much are interchangeable, so only the first
+            // of them is tried
+            if self.loads[..elf].contains(&self.loads[elf]) || self.loads[elf] + item >= self.best.0
+            {
+                continue;
+            }
+            self.loads[elf] += item;
+            self.assignment[index] = elf;
+            self.assign(index + 1);
+            self.loads[elf] -= item;
+        }
+    }
+}
+
+pub fn plan_redistribution(input: &str, elves: usize) -> Plan {
+    plan(split_input(input).concat(), elves, PLAN_SEARCH_NODES)
+}
+
+fn plan(mut items: Vec<u64>, elves: usize, nodes: usize) -> Plan {
+    if elves == 0 {
+        panic!("Expected at least one elf to carry the items");
+    }
+    items.sort_unstable_by(|a, b| b.cmp(a));
+    let total = items.iter().sum::<u64>();
+    let lower_bound = items
+        .first()
+        .copied()
+        .unwrap_or(0)
+        .max(total.div_ceil(elves as u64));
+
+    let greedy = longest_first(&items, elves);
+    let mut loads = vec![0; elves];
+    for (&item, &elf) in items.iter().zip(&greedy) {
+        loads[elf] += item;
+    }
+    let mut search = Search {
+        items: &items,
+        lower_bound,
+        loads: vec![0; elves],
+        assignment: vec![0; items.len()],
+        best: (loads.into_iter().max().unwrap(), greedy),
+        nodes,
+    };
+    search.assign(0);
+    // Without nodes left the search may have stopped short
+    let exact = search.nodes > 0;
+    let best = search.best;
+
+    let mut plan = vec![Vec::new(); elves];
+    for (&item, &elf) in items.iter().zip(&best.1) {
+        plan[elf].push(item);
+    }
+    Plan {
+        elves: plan,
+        optimal: exact || best.0 == lower_bound,
+        lower_bound,
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let heaviest = self.heaviest();
+        if self.optimal {
+            writeln!(
+                formatter,
+                "Heaviest load: {} calories, the best possible",
+                heaviest
+            )?;
+        } else {
+            writeln!(
+                formatter,
+                "Heaviest load: {} calories, at most {:.2}% above the best possible (at least {})",
+                heaviest,
+                (self.worst_ratio() - 1.0) * 100.0,
+                self.lower_bound
+            )?;
+        }
+        for (index, (items, load)) in self.elves.iter().zip(self.loads()).enumerate() {
+            writeln!(
+                formatter,
+                "Elf {}: {} calories in {} items",
+                index + 1,
+                load,
+                items.len()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_input_should_split_on_double_newline() {
+        let test_input = "1000\n\n2000";
+
+        assert_eq!(split_input(test_input), vec![vec![1000], vec![2000]]);
+    }
+
+    #[test]
+    fn part_1_should_return_the_highest_sum() {
+        let test_input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+        assert_eq!(part_1(test_input), 24000);
+    }
+
+    #[test]
+    fn part_2_should_return_the_sum_of_the_top_three_highest_sums() {
+        let test_input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+        assert_eq!(part_2(test_input), 45000);
+    }
+
+    #[test]
+    fn top_k_should_return_the_largest_totals_largest_first() {
+        let test_input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+        assert_eq!(top_k(test_input, 2), vec![24000, 11000]);
+        assert_eq!(top_k(test_input, 9), vec![24000, 11000, 10000, 6000, 4000]);
+        assert_eq!(top_k(test_input, 0), Vec::<u64>::new());
+    }
+
+    #[test]
+    fn top_elves_should_number_the_elves_in_input_order() {
+        let test_input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+        assert_eq!(
+            top_elves(test_input, 2).top,
+            vec![
+                Elf {
+                    number: 4,
+                    rank: 1,
+                    calories: 24000,
+                    items: 3
+                },
+                Elf {
+                    number: 3,
+                    rank: 2,
+                    calories: 11000,
+                    items: 2
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn top_elves_should_share_ranks_and_list_the_ties_left_out() {
+        let test_input = "5\n\n3\n2\n\n9\n\n1\n4\n\n5\n";
+        let ranking = top_elves(test_input, 2);
+
+        assert_eq!(
+            ranking
+                .top
+                .iter()
+                .map(|elf| (elf.number, elf.rank))
+                .collect::<Vec<_>>(),
+            vec![(3, 1), (1, 2)]
+        );
+        assert_eq!(
+            ranking
+                .tied_out
+                .iter()
+                .map(|elf| (elf.number, elf.rank, elf.items))
+                .collect::<Vec<_>>(),
+            vec![(2, 2, 2), (4, 2, 2), (5, 2, 1)]
+        );
+    }
+
+    #[test]
+    fn split_input_should_read_extra_blank_lines_as_elves_carrying_nothing() {
+        assert_eq!(
+            split_input("1\n\n\n2\n\n\n"),
+            vec![vec![1], vec![], vec![2]]
+        );
+    }
+
+    #[test]
+    fn split_input_should_accept_crlf_and_blank_lines_with_spaces() {
+        assert_eq!(
+            split_input("1\r\n2\r\n  \r\n3\n\t\n \n4000000000\n4000000000\n \n"),
+            vec![vec![1, 2], vec![3], vec![], vec![4000000000, 4000000000]]
+        );
+        assert_eq!(part_1("4000000000\n4000000000\n"), 8000000000);
+    }
+
+    #[test]
+    fn try_split_input_should_report_where_a_line_is_invalid() {
+        assert_eq!(
+            try_split_input("1000\n\n20x0\n").unwrap_err().to_string(),
+            "Line 3, column 3: Expected the end of the line, found 'x0'"
+        );
+        assert_eq!(
+            try_split_input("1000\n\n3000\r\nabc\r\n")
+                .unwrap_err()
+                .to_string(),
+            "Line 4, column 1: Expected a number, found 'abc'"
+        );
+    }
+
+    #[test]
+    fn split_input_should_ignore_blank_lines_before_the_first_elf() {
+        assert_eq!(split_input("\n\n1\n"), vec![vec![1]]);
+        assert_eq!(
+            split_input(" \r\n\t\n1\n\n\n2\n"),
+            vec![vec![1], vec![], vec![2]]
+        );
+        assert_eq!(split_input(""), Vec::<Vec<u64>>::new());
+        assert_eq!(split_input("\n \n\n"), Vec::<Vec<u64>>::new());
+    }
+
+    #[test]
+    fn top_k_should_match_sorting_every_total() {
+        let input = (1..=50)
+            .map(|elf| format!("{}\n{}\n", elf * 37 % 101, elf % 7))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut totals = split_input(&input)
+            .iter()
+            .map(|items| items.iter().sum::<u64>())
+            .collect::<Vec<_>>();
+        totals.sort_unstable_by(|a, b| b.cmp(a));
+
+        for k in [0, 1, 3, 50, 60] {
+            assert_eq!(top_k(&input, k), totals[..k.min(totals.len())]);
+        }
+    }
+
+    #[test]
+    fn statistics_should_be_none_without_elves() {
+        assert_eq!(statistics(""), None);
+        assert_eq!(statistics("\n\n"), None);
+    }
+
+    #[test]
+    fn statistics_should_describe_the_totals() {
+        let test_input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n\n7000\n8000\n9000\n\n10000\n";
+        let statistics = statistics(test_input).unwrap();
+
+        assert_eq!(statistics.elves, 6);
+        assert_eq!(statistics.mean, 55000.0 / 6.0);
+        // Of 0, 4000, 6000, 10000, 11000 and 24000
+        assert_eq!(statistics.median, 8000.0);
+        assert_eq!(
+            statistics.percentiles,
+            vec![(10, 0), (25, 4000), (75, 11000), (90, 24000)]
+        );
+        assert_eq!(statistics.histogram[0], (0..=2399, 1));
+        assert_eq!(statistics.histogram[9], (21600..=24000, 1));
+        assert_eq!(statistics.largest_item, Some((10000, 6)));
+        assert_eq!(statistics.empty_elves, vec![4]);
+    }
+
+    #[test]
+    fn plan_redistribution_should_find_better_plans_than_longest_first() {
+        // Longest first gives 3 + 2 + 2 and 3 + 2
+        let plan = plan_redistribution("3\n3\n\n2\n2\n2\n", 2);
+
+        assert!(plan.optimal);
+        assert_eq!(plan.loads(), vec![6, 6]);
+        assert_eq!(plan.worst_ratio(), 1.0);
+    }
+
+    #[test]
+    fn plan_redistribution_should_prove_plans_above_the_lower_bound_optimal() {
+        // 12 calories over 3 elves would be 4 each, but the 3s can't go
+        // together and neither can share with two 2s, so the best is 3 + 2
+        let plan = plan_redistribution("3\n3\n2\n2\n2\n", 3);
+
+        assert!(plan.optimal);
+        assert_eq!(plan.lower_bound, 4);
+        assert_eq!(plan.heaviest(), 5);
+    }
+
+    #[test]
+    fn plan_should_settle_for_the_best_found_when_out_of_nodes() {
+        // Longest first gives 5 + 3 + 3, the best is 9 for each elf
+        let items = vec![5, 5, 4, 4, 3, 3, 3];
+
+        let greedy = plan(items.clone(), 3, 0);
+        assert!(!greedy.optimal);
+        assert_eq!(greedy.heaviest(), 11);
+        assert!((greedy.worst_ratio() - 11.0 / 9.0).abs() < 1e-9);
+
+        let searched = plan(items, 3, PLAN_SEARCH_NODES);
+        assert!(searched.optimal);
+        assert_eq!(searched.loads(), vec![9, 9, 9]);
+    }
+}
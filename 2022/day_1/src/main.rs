@@ -0,0 +1,29 @@
+use y2022_day_1::{part_1, part_2, top_k, INPUT};
+
+fn main() {
+    let mut args: Vec<String> = std::env::args().collect();
+    // `--top <k>` also adds up the `k` elves carrying the most
+    let top = args.iter().position(|arg| arg == "--top").map(|index| {
+        args.remove(index);
+        (index < args.len())
+            .then(|| args.remove(index))
+            .and_then(|k| k.parse::<usize>().ok())
+            .unwrap_or_else(|| panic!("Expected a number of elves after --top"))
+    });
+    let input = match (args.get(1), INPUT) {
+        (Some(path), _) => std::fs::read_to_string(path)
+            .unwrap_or_else(|error| panic!("No file found at: '{}'.\n{}", path, error)),
+        (None, Some(input)) => input.to_string(),
+        (None, None) => panic!("Expected the path to the puzzle input"),
+    };
+
+    let part_1_result = part_1(&input);
+    println!("Day 1 Part 1: {}", part_1_result);
+
+    let part_2_result = part_2(&input);
+    println!("Day 1 Part 2: {}", part_2_result);
+
+    if let Some(k) = top {
+        println!("Top {}: {}", k, top_k(&input, k).iter().sum::<u64>());
+    }
+}
@@ -1,10 +1,33 @@
+use common::{
+    parse::parse,
+    trace::{Trace, Value},
+};
+
+#[cfg(feature = "embed-input")]
+pub const INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embed-input"))]
+pub const INPUT: Option<&str> = None;
+
 enum Operation {
     Addx(i32),
     Noop,
 }
 
+// Lines like 'addx -5' or 'noop'
+fn parse_program(input: &str) -> Vec<Operation> {
+    parse(input, |parser| {
+        parser.lines(
+            |parser| match parser.one_of(&[("addx ", true), ("noop", false)])? {
+                true => Ok(Operation::Addx(parser.number()?)),
+                false => Ok(Operation::Noop),
+            },
+        )
+    })
+    .unwrap_or_else(|error| panic!("{}", error))
+}
+
 fn cycle(cycle: u32, x_register: i32) -> Option<i32> {
-    if cycle >= 60 && (cycle - 20) % 40 == 0 {
+    if cycle >= 60 && (cycle - 20).is_multiple_of(40) {
         Some(x_register * cycle as i32)
     } else if cycle == 20 {
         Some(x_register * 20)
@@ -13,61 +36,64 @@ fn cycle(cycle: u32, x_register: i32) -> Option<i32> {
     }
 }
 
-fn part_1(input: &str) -> i32 {
+fn register_state(cycle: usize, x_register: i32) -> Value {
+    Value::Object(vec![
+        ("cycle", Value::Number(cycle as i64)),
+        ("x", Value::Number(x_register.into())),
+    ])
+}
+
+// A step is traced for every cycle, with the value of X during that cycle
+pub fn part_1_traced(input: &str, trace: &mut impl Trace) -> i32 {
     let mut current_cycle: u32 = 0;
     let mut x_register = 1;
-    input
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| {
-            if line.starts_with("addx") {
-                let (_, value) = line.split_once(' ').unwrap();
-                let value = value.parse().unwrap();
-                Operation::Addx(value)
-            } else {
-                Operation::Noop
-            }
-        })
+    parse_program(input)
+        .into_iter()
         .map(|operation| match operation {
             Operation::Addx(value) => {
                 let mut cycle_results = 0;
                 current_cycle += 1;
+                trace.step(|| register_state(current_cycle as usize, x_register));
                 cycle_results += cycle(current_cycle, x_register).unwrap_or(0);
                 current_cycle += 1;
+                trace.step(|| register_state(current_cycle as usize, x_register));
                 cycle_results += cycle(current_cycle, x_register).unwrap_or(0);
                 x_register += value;
                 cycle_results
             }
             Operation::Noop => {
                 current_cycle += 1;
+                trace.step(|| register_state(current_cycle as usize, x_register));
                 cycle(current_cycle, x_register).unwrap_or(0)
             }
         })
         .sum()
 }
 
-fn part_2(input: &str) {
-    let mut operations = input.lines().filter(|line| !line.is_empty()).map(|line| {
-        if line.starts_with("addx") {
-            let (_, value) = line.split_once(' ').unwrap();
-            let value = value.parse().unwrap();
-            Operation::Addx(value)
-        } else {
-            Operation::Noop
-        }
-    });
+pub fn part_1(input: &str) -> i32 {
+    part_1_traced(input, &mut ())
+}
+
+pub const SCREEN_WIDTH: usize = 40;
+pub const SCREEN_HEIGHT: usize = 6;
 
-    let mut output: [char; 240] = [' '; 240];
+// A step is traced for every cycle, with the value of X during that cycle and
+// whether the pixel drawn was lit
+pub fn render_traced(input: &str, width: usize, height: usize, trace: &mut impl Trace) -> String {
+    let mut operations = parse_program(input).into_iter();
+
+    let mut output = vec![' '; width * height];
     let mut x_register = 2;
     let mut current_operation: Operation = Operation::Noop;
     let mut cycle_to_take_new_operation_at = 1;
     let mut value_to_add_to_x: i32 = 0;
-    for cycle in 1..=240 {
+    for cycle in 1..=width * height {
         if cycle == cycle_to_take_new_operation_at {
             if matches!(current_operation, Operation::Addx(_)) {
                 x_register += value_to_add_to_x;
             }
-            current_operation = operations.next().unwrap();
+            // Once the program runs out the register keeps its value
+            current_operation = operations.next().unwrap_or(Operation::Noop);
             match current_operation {
                 Operation::Addx(value) => {
                     value_to_add_to_x = value;
@@ -79,31 +105,35 @@ fn part_2(input: &str) {
             }
         }
 
-        let sprite_position = cycle % 40;
+        let sprite_position = (cycle % width) as i32;
         if sprite_position == x_register
             || sprite_position == x_register - 1
             || sprite_position == x_register + 1
         {
-            output[cycle as usize - 1] = '#';
+            output[cycle - 1] = '#';
         }
+        trace.step(|| {
+            Value::Object(vec![
+                ("cycle", Value::Number(cycle as i64)),
+                ("x", Value::Number((x_register - 1).into())),
+                ("lit", Value::Bool(output[cycle - 1] == '#')),
+            ])
+        });
     }
 
-    println!("Day 10 Part 2:");
-    output.chunks(40).for_each(|x| {
-        println!("{}", x.iter().collect::<String>());
-    })
+    output
+        .chunks(width)
+        .map(|x| x.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    let path = &args[1];
-    let input = std::fs::read_to_string(path)
-        .unwrap_or_else(|error| panic!("No file found at: '{}'.\n{}", path, error));
-
-    let part_1_result = part_1(&input);
-    println!("Day 10 Part 1: {}", part_1_result);
+pub fn render(input: &str, width: usize, height: usize) -> String {
+    render_traced(input, width, height, &mut ())
+}
 
-    part_2(&input);
+pub fn part_2(input: &str) -> String {
+    render(input, SCREEN_WIDTH, SCREEN_HEIGHT)
 }
 
 #[cfg(test)]
@@ -0,0 +1,342 @@
+use common::{
+    explore::{Command, Explore},
+    parse::{parse, Parser},
+    trace::{Trace, Value},
+};
+
+#[cfg(feature = "embed-input")]
+pub const INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embed-input"))]
+pub const INPUT: Option<&str> = None;
+
+#[derive(Debug)]
+enum Operation {
+    Add(u64),
+    Multiply(u64),
+    Square,
+}
+
+#[derive(Debug)]
+struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    test: u64,           // Divisible by
+    true_target: usize,  // Who to throw to if test returns true
+    false_target: usize, // Who to throw to if test returns false
+}
+
+#[derive(Clone, Copy)]
+enum Operator {
+    Add,
+    Multiply,
+}
+
+fn parse_monkey(parser: &mut Parser) -> common::parse::Result<Monkey> {
+    parser.literal("Monkey ")?;
+    parser.number::<usize>()?;
+    parser.literal(":")?;
+    parser.line_end()?;
+
+    parser.literal("  Starting items: ")?;
+    let items = if parser.at_line_end() {
+        Vec::new()
+    } else {
+        parser.separated(", ", Parser::number)?
+    };
+    parser.line_end()?;
+
+    parser.literal("  Operation: new = old ")?;
+    let operator = parser.one_of(&[("+ ", Operator::Add), ("* ", Operator::Multiply)])?;
+    let operation = match operator {
+        Operator::Multiply if parser.peek("old") => {
+            parser.literal("old")?;
+            Operation::Square
+        }
+        Operator::Multiply => Operation::Multiply(parser.number()?),
+        Operator::Add => Operation::Add(parser.number()?),
+    };
+    parser.line_end()?;
+
+    parser.literal("  Test: divisible by ")?;
+    let test = parser.number()?;
+    parser.line_end()?;
+    parser.literal("    If true: throw to monkey ")?;
+    let true_target = parser.number()?;
+    parser.line_end()?;
+    parser.literal("    If false: throw to monkey ")?;
+    let false_target = parser.number()?;
+    parser.line_end()?;
+
+    Ok(Monkey {
+        items,
+        operation,
+        test,
+        true_target,
+        false_target,
+    })
+}
+
+fn create_monkey_set(input: &str) -> Vec<Monkey> {
+    parse(input, |parser| parser.blocks(parse_monkey)).unwrap_or_else(|error| panic!("{}", error))
+}
+
+// The items each monkey holds and how many items each has inspected so far
+fn monkeys_state(round: usize, monkeys: &[Monkey], inspection_counts: &[usize]) -> Value {
+    Value::Object(vec![
+        ("round", Value::Number(round as i64)),
+        (
+            "items",
+            Value::List(
+                monkeys
+                    .iter()
+                    .map(|monkey| {
+                        Value::List(
+                            monkey
+                                .items
+                                .iter()
+                                .map(|&item| Value::Number(item as i64))
+                                .collect(),
+                        )
+                    })
+                    .collect(),
+            ),
+        ),
+        (
+            "inspections",
+            Value::List(
+                inspection_counts
+                    .iter()
+                    .map(|&count| Value::Number(count as i64))
+                    .collect(),
+            ),
+        ),
+    ])
+}
+
+fn evaluate_rounds(
+    mut monkeys: Vec<Monkey>,
+    rounds: usize,
+    worry_reducer: impl Fn(u64) -> u64,
+    trace: &mut impl Trace,
+) -> Vec<usize> {
+    let mut inspection_counts = vec![0; monkeys.len()];
+    trace.step(|| monkeys_state(0, &monkeys, &inspection_counts));
+    for round in 1..=rounds {
+        for monkey_index in 0..monkeys.len() {
+            let inspected_items = monkeys[monkey_index]
+                .items
+                .iter()
+                .map(|item| {
+                    let new_item = match monkeys[monkey_index].operation {
+                        Operation::Add(value) => item + value,
+                        Operation::Multiply(value) => item * value,
+                        Operation::Square => item.pow(2),
+                    };
+
+                    worry_reducer(new_item)
+                })
+                .collect::<Vec<_>>();
+            monkeys[monkey_index].items.clear();
+            inspection_counts[monkey_index] += inspected_items.len();
+
+            // Throw items
+            for item in inspected_items {
+                let target = if item % monkeys[monkey_index].test == 0 {
+                    monkeys[monkey_index].true_target
+                } else {
+                    monkeys[monkey_index].false_target
+                };
+                monkeys[target].items.push(item);
+            }
+        }
+        trace.step(|| monkeys_state(round, &monkeys, &inspection_counts));
+    }
+    inspection_counts
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonkeyBusiness {
+    // The numbers of the two monkeys that inspected the most items and how
+    // many they inspected, most first
+    pub most_active: Vec<(usize, usize)>,
+    pub level: usize,
+}
+
+fn most_active(inspection_counts: Vec<usize>) -> MonkeyBusiness {
+    let mut monkeys = inspection_counts
+        .into_iter()
+        .enumerate()
+        .collect::<Vec<_>>();
+    monkeys.sort_by_key(|&(monkey, inspections)| (std::cmp::Reverse(inspections), monkey));
+    monkeys.truncate(2);
+    MonkeyBusiness {
+        level: match monkeys[..] {
+            [(_, a), (_, b)] => a * b,
+            _ => 0,
+        },
+        most_active: monkeys,
+    }
+}
+
+pub const PART_1_ROUNDS: usize = 20;
+pub const PART_2_ROUNDS: usize = 10000;
+pub const RELIEF: u64 = 3;
+
+fn greatest_common_divisor(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        greatest_common_divisor(b, a % b)
+    }
+}
+
+// Worry levels are divided by `relief` after each inspection. Without any
+// relief they would grow without bound, so they're kept below the least common
+// multiple of the monkeys' tests instead, which leaves every test's outcome
+// unchanged.
+// A step is traced after every round.
+pub fn monkey_business_traced(
+    input: &str,
+    rounds: usize,
+    relief: u64,
+    trace: &mut impl Trace,
+) -> MonkeyBusiness {
+    let monkeys = create_monkey_set(input);
+    let inspection_counts = if relief > 1 {
+        evaluate_rounds(monkeys, rounds, |item| item / relief, trace)
+    } else {
+        let lcm = monkeys.iter().fold(1, |lcm, monkey| {
+            lcm / greatest_common_divisor(lcm, monkey.test) * monkey.test
+        });
+        evaluate_rounds(monkeys, rounds, |item| item % lcm, trace)
+    };
+    most_active(inspection_counts)
+}
+
+pub fn monkey_business(input: &str, rounds: usize, relief: u64) -> MonkeyBusiness {
+    monkey_business_traced(input, rounds, relief, &mut ())
+}
+
+pub fn part_1(input: &str) -> usize {
+    monkey_business(input, PART_1_ROUNDS, RELIEF).level
+}
+
+pub fn part_2(input: &str) -> usize {
+    monkey_business(input, PART_2_ROUNDS, 1).level
+}
+
+fn describe_operation(operation: &Operation) -> String {
+    match operation {
+        Operation::Add(value) => format!("old + {}", value),
+        Operation::Multiply(value) => format!("old * {}", value),
+        Operation::Square => "old * old".to_string(),
+    }
+}
+
+// Adds `factor - 1` copies of the monkeys, each copy throwing only among
+// itself, so every round takes `factor` times the work
+pub fn scale_input(input: &str, factor: usize) -> String {
+    let monkeys = create_monkey_set(input);
+    let mut copies = Vec::new();
+    for copy in 0..factor {
+        let offset = copy * monkeys.len();
+        for (index, monkey) in monkeys.iter().enumerate() {
+            copies.push(format!(
+                "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                offset + index,
+                monkey
+                    .items
+                    .iter()
+                    .map(|item| item.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                describe_operation(&monkey.operation),
+                monkey.test,
+                offset + monkey.true_target,
+                offset + monkey.false_target
+            ));
+        }
+    }
+    copies.join("\n")
+}
+
+fn describe_monkey(index: usize, monkey: &Monkey) -> String {
+    let operation = describe_operation(&monkey.operation);
+    format!(
+        "Monkey {}:\n  Items: {:?}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}",
+        index,
+        monkey.items,
+        operation,
+        monkey.test,
+        monkey.true_target,
+        monkey.false_target
+    )
+}
+
+pub struct Monkeys {
+    monkeys: Vec<Monkey>,
+}
+
+impl Monkeys {
+    pub fn new(input: &str) -> Monkeys {
+        Monkeys {
+            monkeys: create_monkey_set(input),
+        }
+    }
+}
+
+impl Explore for Monkeys {
+    fn commands(&self) -> &'static [Command] {
+        &[Command {
+            usage: "monkey <n>",
+            description: "Starting items, operation and test of a monkey",
+        }]
+    }
+
+    fn execute(&mut self, command: &str, arguments: &[&str]) -> Option<Result<String, String>> {
+        if command != "monkey" {
+            return None;
+        }
+        let monkey = arguments
+            .first()
+            .and_then(|index| index.parse::<usize>().ok())
+            .and_then(|index| Some((index, self.monkeys.get(index)?)));
+        Some(match monkey {
+            Some((index, monkey)) => Ok(describe_monkey(index, monkey)),
+            None => Err(format!(
+                "Expected a monkey between 0 and {}",
+                self.monkeys.len().saturating_sub(1)
+            )),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_input_should_copy_the_monkeys() {
+        let input = "Monkey 0:\n  Starting items: 79, 98\n  Operation: new = old * 19\n  Test: divisible by 23\n    If true: throw to monkey 1\n    If false: throw to monkey 1\n\nMonkey 1:\n  Starting items: 54\n  Operation: new = old * old\n  Test: divisible by 19\n    If true: throw to monkey 0\n    If false: throw to monkey 0\n";
+        let scaled = scale_input(input, 3);
+        let monkeys = create_monkey_set(&scaled);
+
+        assert_eq!(monkeys.len(), 6);
+        assert_eq!(monkeys[4].items, vec![79, 98]);
+        assert_eq!(monkeys[4].true_target, 5);
+        assert!(matches!(monkeys[5].operation, Operation::Square));
+    }
+
+    #[test]
+    fn most_active_should_pick_the_two_busiest_monkeys() {
+        assert_eq!(
+            most_active(vec![101, 95, 7, 105]),
+            MonkeyBusiness {
+                most_active: vec![(3, 105), (0, 101)],
+                level: 105 * 101
+            }
+        );
+        // An odd number of monkeys
+        assert_eq!(most_active(vec![4, 2, 3]).level, 12);
+    }
+}
@@ -0,0 +1,430 @@
+use common::explore::{Command, Explore};
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, VecDeque},
+};
+
+#[cfg(feature = "embed-input")]
+pub const INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embed-input"))]
+pub const INPUT: Option<&str> = None;
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+struct State {
+    cost: usize,
+    position: (usize, usize),
+}
+
+// The priority queue depends on `Ord`.
+// Explicitly implement the trait so the queue becomes a min-heap
+// instead of a max-heap.
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Notice that the we flip the ordering on costs.
+        // In case of a tie we compare positions - this step is necessary
+        // to make implementations of `PartialEq` and `Ord` consistent.
+        other
+            .cost
+            .cmp(&self.cost)
+            .then_with(|| self.position.cmp(&other.position))
+    }
+}
+
+// `PartialOrd` needs to be implemented as well.
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn convert_character(character: char) -> u8 {
+    if character == 'S' {
+        b'a'
+    } else if character == 'E' {
+        b'z'
+    } else {
+        character as u8
+    }
+}
+
+fn can_move_to_character(current_character: char, neighbor_character: char) -> bool {
+    let current_character = convert_character(current_character);
+    let neighbor_character = convert_character(neighbor_character);
+
+    neighbor_character <= current_character || neighbor_character == current_character + 1
+}
+
+fn get_adjacent_indices(
+    y: usize,
+    x: usize,
+    max_y: usize,
+    max_x: usize,
+    map: &[Vec<char>],
+) -> Vec<(usize, usize)> {
+    let mut adjacent_indices: Vec<(usize, usize)> = Vec::new();
+    let current_character = map[y][x];
+
+    if y > 0 {
+        let neighbor_character = map[y - 1][x];
+
+        if can_move_to_character(current_character, neighbor_character) {
+            adjacent_indices.push((y - 1, x))
+        }
+    };
+    if y < max_y {
+        let neighbor_character = map[y + 1][x];
+        if can_move_to_character(current_character, neighbor_character) {
+            adjacent_indices.push((y + 1, x))
+        }
+    };
+    if x > 0 {
+        let neighbor_character = map[y][x - 1];
+        if can_move_to_character(current_character, neighbor_character) {
+            adjacent_indices.push((y, x - 1))
+        }
+    };
+    if x < max_x {
+        let neighbor_character = map[y][x + 1];
+        if can_move_to_character(current_character, neighbor_character) {
+            adjacent_indices.push((y, x + 1))
+        }
+    };
+
+    adjacent_indices
+}
+
+fn dijkstras(
+    input: &[Vec<char>],
+    start_position: (usize, usize),
+    target: (usize, usize),
+) -> Option<usize> {
+    let (target_y, target_x) = target;
+    let max_y = input.len() - 1;
+    let max_x = input[0].len() - 1;
+
+    let mut nodes_to_explore = BinaryHeap::new();
+    nodes_to_explore.push(State {
+        cost: 0,
+        position: start_position,
+    });
+    let mut f_score: HashMap<(usize, usize), u32> = HashMap::from([(start_position, 0)]);
+
+    while let Some(State { cost, position }) = nodes_to_explore.pop() {
+        if position == (target_y, target_x) {
+            return Some(cost);
+        }
+
+        if cost > *f_score.entry(position).or_insert(u32::MAX) as usize {
+            continue;
+        }
+
+        for (neighbor_y, neighbor_x) in
+            get_adjacent_indices(position.0, position.1, max_y, max_x, input)
+        {
+            let next = State {
+                cost: cost + 1,
+                position: (neighbor_y, neighbor_x),
+            };
+
+            let f_score_entry = f_score.entry(next.position).or_insert(u32::MAX);
+            if next.cost < *f_score_entry as usize {
+                nodes_to_explore.push(next);
+                // Relaxation, we have now found a better way
+                *f_score_entry = next.cost as u32;
+            }
+        }
+    }
+
+    None
+}
+
+fn parse_map(input: &str) -> Vec<Vec<char>> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.chars().collect())
+        .collect()
+}
+
+fn find_character(map: &[Vec<char>], target: char) -> Option<(usize, usize)> {
+    map.iter().enumerate().find_map(|(y_index, chars)| {
+        chars
+            .iter()
+            .enumerate()
+            .find_map(|(x_index, character)| {
+                if *character == target {
+                    Some(x_index)
+                } else {
+                    None
+                }
+            })
+            .map(|x_index| (y_index, x_index))
+    })
+}
+
+pub fn part_1(input: &str) -> usize {
+    let map = parse_map(input);
+    let end_point = find_character(&map, 'E').unwrap();
+    let start_point = find_character(&map, 'S').unwrap();
+
+    dijkstras(&map, start_point, end_point).unwrap()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Route {
+    // Row and column of the square the route starts at
+    pub start: (usize, usize),
+    pub steps: usize,
+}
+
+// The lowest square with the shortest route to the best signal
+pub fn best_start(input: &str) -> Route {
+    let map = parse_map(input);
+    let end_point = find_character(&map, 'E').unwrap();
+    map.iter()
+        .enumerate()
+        .flat_map(|(y_index, chars)| {
+            chars
+                .iter()
+                .enumerate()
+                .filter_map(|(x_index, character)| {
+                    if *character == 'S' || *character == 'a' {
+                        Some(x_index)
+                    } else {
+                        None
+                    }
+                })
+                .map(move |x_index| (y_index, x_index))
+        })
+        .filter_map(|start_position| {
+            dijkstras(&map, start_position, end_point).map(|steps| Route {
+                start: start_position,
+                steps,
+            })
+        })
+        .min_by_key(|route| (route.steps, route.start))
+        .unwrap()
+}
+
+pub fn part_2(input: &str) -> usize {
+    best_start(input).steps
+}
+
+// The fewest steps from every square to the best signal, found with one
+// breadth-first search walking backwards from the end
+fn steps_to_end(map: &[Vec<char>]) -> HashMap<(usize, usize), usize> {
+    let end_point = find_character(map, 'E').unwrap();
+    let max_y = map.len() - 1;
+    let max_x = map[0].len() - 1;
+    let mut steps = HashMap::from([(end_point, 0)]);
+    let mut nodes_to_explore = VecDeque::from([end_point]);
+    while let Some((y, x)) = nodes_to_explore.pop_front() {
+        let neighbors = [
+            (y.wrapping_sub(1), x),
+            (y + 1, x),
+            (y, x.wrapping_sub(1)),
+            (y, x + 1),
+        ];
+        for (neighbor_y, neighbor_x) in neighbors {
+            if neighbor_y > max_y
+                || neighbor_x > max_x
+                || steps.contains_key(&(neighbor_y, neighbor_x))
+                || !can_move_to_character(map[neighbor_y][neighbor_x], map[y][x])
+            {
+                continue;
+            }
+            steps.insert((neighbor_y, neighbor_x), steps[&(y, x)] + 1);
+            nodes_to_explore.push_back((neighbor_y, neighbor_x));
+        }
+    }
+    steps
+}
+
+pub fn part_1_reverse_bfs(input: &str) -> usize {
+    let map = parse_map(input);
+    let start_point = find_character(&map, 'S').unwrap();
+    steps_to_end(&map)[&start_point]
+}
+
+pub fn part_2_reverse_bfs(input: &str) -> usize {
+    let map = parse_map(input);
+    steps_to_end(&map)
+        .into_iter()
+        .filter(|&((y, x), _)| map[y][x] == 'S' || map[y][x] == 'a')
+        .map(|(_, steps)| steps)
+        .min()
+        .unwrap()
+}
+
+// The squares of one of the shortest routes from the start to the best
+// signal, both included
+pub fn shortest_path(input: &str) -> Option<Vec<(usize, usize)>> {
+    let map = parse_map(input);
+    let start_point = find_character(&map, 'S')?;
+    let end_point = find_character(&map, 'E')?;
+    let max_y = map.len() - 1;
+    let max_x = map[0].len() - 1;
+
+    let mut previous: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
+    let mut nodes_to_explore = VecDeque::from([start_point]);
+    while let Some(position) = nodes_to_explore.pop_front() {
+        if position == end_point {
+            let mut path = vec![end_point];
+            while let Some(&step) = previous.get(path.last().unwrap()) {
+                path.push(step);
+            }
+            path.reverse();
+            return Some(path);
+        }
+
+        for neighbor in get_adjacent_indices(position.0, position.1, max_y, max_x, &map) {
+            if neighbor != start_point && !previous.contains_key(&neighbor) {
+                previous.insert(neighbor, position);
+                nodes_to_explore.push_back(neighbor);
+            }
+        }
+    }
+
+    None
+}
+
+pub struct Heightmap {
+    map: Vec<Vec<char>>,
+    end_point: (usize, usize),
+}
+
+impl Heightmap {
+    pub fn new(input: &str) -> Heightmap {
+        let map = parse_map(input);
+        let end_point = find_character(&map, 'E').unwrap();
+        Heightmap { map, end_point }
+    }
+}
+
+impl Explore for Heightmap {
+    fn commands(&self) -> &'static [Command] {
+        &[Command {
+            usage: "path <row>,<column>",
+            description: "Fewest steps from a square to the best signal, e.g. 'path 0,0'",
+        }]
+    }
+
+    fn execute(&mut self, command: &str, arguments: &[&str]) -> Option<Result<String, String>> {
+        if command != "path" {
+            return None;
+        }
+        let position = arguments
+            .first()
+            .and_then(|position| position.split_once(','))
+            .and_then(|(y, x)| {
+                Some((
+                    y.trim().parse::<usize>().ok()?,
+                    x.trim().parse::<usize>().ok()?,
+                ))
+            })
+            .filter(|&(y, x)| y < self.map.len() && x < self.map[y].len());
+        let Some(position) = position else {
+            return Some(Err(format!(
+                "Expected a position between 0,0 and {},{}",
+                self.map.len() - 1,
+                self.map[0].len() - 1
+            )));
+        };
+
+        Some(Ok(match dijkstras(&self.map, position, self.end_point) {
+            Some(steps) => format!(
+                "{} steps from '{}'",
+                steps, self.map[position.0][position.1]
+            ),
+            None => format!(
+                "The best signal can't be reached from '{}'",
+                self.map[position.0][position.1]
+            ),
+        }))
+    }
+}
+
+// Stretches every square into a block of about `factor` squares of the same
+// height, keeping a single start and end, so the same routes exist but are
+// longer.
+pub fn scale_input(input: &str, factor: usize) -> String {
+    let size = (factor as f64).sqrt().ceil() as usize;
+    let mut scaled = String::new();
+    for row in parse_map(input) {
+        for block_y in 0..size {
+            for &square in &row {
+                for block_x in 0..size {
+                    scaled.push(match square {
+                        'S' | 'E' if block_y > 0 || block_x > 0 => {
+                            convert_character(square) as char
+                        }
+                        square => square,
+                    });
+                }
+            }
+            scaled.push('\n');
+        }
+    }
+    scaled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_should_return_fewest_amount_of_steps() {
+        let input = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi\n\n";
+
+        assert_eq!(part_1(input), 31);
+    }
+
+    #[test]
+    fn part_2_should_return_fewest_amount_of_steps() {
+        let input = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi\n\n";
+
+        assert_eq!(part_2(input), 29);
+    }
+
+    #[test]
+    fn best_start_should_find_the_closest_lowest_square() {
+        let input = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi\n\n";
+
+        assert_eq!(
+            best_start(input),
+            Route {
+                start: (4, 0),
+                steps: 29
+            }
+        );
+    }
+
+    #[test]
+    fn reverse_bfs_should_match_dijkstras() {
+        let input = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi\n\n";
+
+        assert_eq!(part_1_reverse_bfs(input), 31);
+        assert_eq!(part_2_reverse_bfs(input), 29);
+    }
+
+    #[test]
+    fn shortest_path_should_go_from_the_start_to_the_best_signal() {
+        let input = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi\n\n";
+        let path = shortest_path(input).unwrap();
+
+        assert_eq!(path.len() - 1, part_1(input));
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(2, 5)));
+    }
+
+    #[test]
+    fn scale_input_should_stretch_the_routes() {
+        let input = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi\n\n";
+        let scaled = scale_input(input, 4);
+
+        assert_eq!(scaled.lines().count(), 10);
+        assert_eq!(scaled.matches('S').count(), 1);
+        // Turns can cut across a block, so routes up to twice as long
+        assert!(part_1(&scaled) > 31 && part_1(&scaled) <= 31 * 2);
+    }
+}
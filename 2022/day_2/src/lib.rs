@@ -0,0 +1,974 @@
+use common::{
+    csv,
+    parse::{parse, Parser},
+};
+use std::{cmp::Reverse, fmt, str::FromStr};
+
+#[cfg(feature = "embed-input")]
+pub const INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embed-input"))]
+pub const INPUT: Option<&str> = None;
+
+// The puzzle's game. A definition has a line per shape with its name, the
+// opponent's symbol, our symbol and its value, and a line per outcome with
+// our symbol for it and its points. Each shape beats the half of the others
+// listed before it, counting round from the end, so there must be an odd
+// number of them.
+pub const ROCK_PAPER_SCISSORS: &str = "\
+shape Rock A X 1
+shape Paper B Y 2
+shape Scissors C Z 3
+outcome lose X 0
+outcome draw Y 3
+outcome win Z 6
+";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Shape {
+    pub name: String,
+    pub opponent_symbol: String,
+    pub symbol: String,
+    pub value: u16,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Game {
+    pub shapes: Vec<Shape>,
+    // Our symbol and the points of losing, drawing and winning
+    pub outcomes: [(String, u16); 3],
+}
+
+// The mapping modes try every order of the shapes, 5040 of them for 7
+pub const MAX_SHAPES: usize = 7;
+
+// `one_of` takes the first symbol that matches, so a symbol must not start
+// with another of its column
+fn clashes(symbol: &str, other: &str) -> bool {
+    symbol.starts_with(other) || other.starts_with(symbol)
+}
+
+impl Game {
+    pub fn parse(definition: &str) -> common::parse::Result<Game> {
+        parse(definition, |parser| {
+            let mut shapes = Vec::new();
+            let mut outcomes: [Option<(String, u16)>; 3] = [None, None, None];
+            parser.lines(|parser| {
+                let start = *parser;
+                if parser.one_of(&[("shape ", true), ("outcome ", false)])? {
+                    let name = parser.word()?.to_string();
+                    if shapes.iter().any(|shape: &Shape| shape.name == name) {
+                        return Err(start.error(format!("{} is defined twice", name)));
+                    }
+                    parser.literal(" ")?;
+                    let at = *parser;
+                    let opponent_symbol = parser.word()?.to_string();
+                    if let Some(shape) = shapes
+                        .iter()
+                        .find(|shape| clashes(&opponent_symbol, &shape.opponent_symbol))
+                    {
+                        return Err(at.error(format!(
+                            "The opponent's '{}' can't be told apart from {}'s '{}'",
+                            opponent_symbol, shape.name, shape.opponent_symbol
+                        )));
+                    }
+                    parser.literal(" ")?;
+                    let at = *parser;
+                    let symbol = parser.word()?.to_string();
+                    if let Some(shape) = shapes.iter().find(|shape| clashes(&symbol, &shape.symbol))
+                    {
+                        return Err(at.error(format!(
+                            "Our '{}' can't be told apart from {}'s '{}'",
+                            symbol, shape.name, shape.symbol
+                        )));
+                    }
+                    parser.literal(" ")?;
+                    shapes.push(Shape {
+                        name,
+                        opponent_symbol,
+                        symbol,
+                        value: parser.number()?,
+                    });
+                } else {
+                    let outcome = parser.one_of(&[
+                        ("lose", Outcome::Lose),
+                        ("draw", Outcome::Draw),
+                        ("win", Outcome::Win),
+                    ])?;
+                    parser.literal(" ")?;
+                    let at = *parser;
+                    let symbol = parser.word()?.to_string();
+                    let taken = [Outcome::Lose, Outcome::Draw, Outcome::Win]
+                        .into_iter()
+                        .zip(&outcomes)
+                        .find_map(|(other, used)| match used {
+                            Some((used, _)) if clashes(&symbol, used) => Some((other, used)),
+                            _ => None,
+                        });
+                    if let Some((other, used)) = taken {
+                        return Err(at.error(format!(
+                            "Our '{}' can't be told apart from {:?}'s '{}'",
+                            symbol, other, used
+                        )));
+                    }
+                    parser.literal(" ")?;
+                    let points = parser.number()?;
+                    if outcomes[outcome as usize]
+                        .replace((symbol, points))
+                        .is_some()
+                    {
+                        return Err(start.error(format!("{:?} is defined twice", outcome)));
+                    }
+                }
+                Ok(())
+            })?;
+
+            if shapes.len() < 3 || shapes.len() % 2 == 0 {
+                return Err(parser.error(format!(
+                    "Expected an odd number of shapes, at least 3, found {}",
+                    shapes.len()
+                )));
+            }
+            if shapes.len() > MAX_SHAPES {
+                return Err(parser.error(format!(
+                    "Expected at most {} shapes, found {}",
+                    MAX_SHAPES,
+                    shapes.len()
+                )));
+            }
+            let [Some(lose), Some(draw), Some(win)] = outcomes else {
+                return Err(parser.error("Expected a line for each of lose, draw and win"));
+            };
+            Ok(Game {
+                shapes,
+                outcomes: [lose, draw, win],
+            })
+        })
+    }
+
+    // A value for each shape in the order of the definition, e.g. "1,2,3"
+    pub fn set_values(&mut self, values: &str) -> Result<(), String> {
+        let values = parse(values, |parser| {
+            parser.separated(",", Parser::number::<u16>)
+        })
+        .map_err(|error| error.to_string())?;
+        if values.len() != self.shapes.len() {
+            return Err(format!(
+                "Expected {} shape values, found {}",
+                self.shapes.len(),
+                values.len()
+            ));
+        }
+        for (shape, value) in self.shapes.iter_mut().zip(values) {
+            shape.value = value;
+        }
+        Ok(())
+    }
+
+    pub fn set_points(&mut self, outcome: Outcome, points: u16) {
+        self.outcomes[outcome as usize].1 = points;
+    }
+
+    // How a round goes for us
+    pub fn outcome(&self, opponent: usize, ours: usize) -> Outcome {
+        let count = self.shapes.len();
+        match (ours + count - opponent) % count {
+            0 => Outcome::Draw,
+            distance if distance <= count / 2 => Outcome::Win,
+            _ => Outcome::Lose,
+        }
+    }
+
+    pub fn score(&self, opponent: usize, ours: usize) -> u16 {
+        self.shapes[ours].value + self.outcomes[self.outcome(opponent, ours) as usize].1
+    }
+
+    // Of the shapes with that outcome, the one worth the most
+    pub fn shape_for(&self, opponent: usize, outcome: Outcome) -> usize {
+        (0..self.shapes.len())
+            .filter(|&ours| self.outcome(opponent, ours) == outcome)
+            .max_by_key(|&ours| (self.shapes[ours].value, Reverse(ours)))
+            .unwrap()
+    }
+
+    // Each line is the opponent's shape, a space, and then the second column
+    fn strategy_guide<T: Copy>(
+        &self,
+        string: &str,
+        second_column: &[(&str, T)],
+    ) -> Vec<(usize, T)> {
+        let opponent = self
+            .shapes
+            .iter()
+            .enumerate()
+            .map(|(index, shape)| (shape.opponent_symbol.as_str(), index))
+            .collect::<Vec<_>>();
+        parse(string, |parser| {
+            parser.lines(|parser| {
+                let shape = parser.one_of(&opponent)?;
+                parser.literal(" ")?;
+                Ok((shape, parser.one_of(second_column)?))
+            })
+        })
+        .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    // The second column is our shape
+    pub fn shapes_played(&self, string: &str) -> Vec<(usize, usize)> {
+        let ours = self
+            .shapes
+            .iter()
+            .enumerate()
+            .map(|(index, shape)| (shape.symbol.as_str(), index))
+            .collect::<Vec<_>>();
+        self.strategy_guide(string, &ours)
+    }
+
+    // The second column is how the round has to end
+    pub fn outcomes_wanted(&self, string: &str) -> Vec<(usize, Outcome)> {
+        let [lose, draw, win] = &self.outcomes;
+        self.strategy_guide(
+            string,
+            &[
+                (lose.0.as_str(), Outcome::Lose),
+                (draw.0.as_str(), Outcome::Draw),
+                (win.0.as_str(), Outcome::Win),
+            ],
+        )
+    }
+
+    pub fn part_1(&self, input: &str) -> u32 {
+        self.shapes_played(input)
+            .iter()
+            .map(|&(opponent, ours)| u32::from(self.score(opponent, ours)))
+            .sum()
+    }
+
+    pub fn part_2(&self, input: &str) -> u32 {
+        self.outcomes_wanted(input)
+            .iter()
+            .map(|&(opponent, outcome)| {
+                u32::from(self.score(opponent, self.shape_for(opponent, outcome)))
+            })
+            .sum()
+    }
+}
+
+// What each of our symbols is read as, and what the guide scores when read
+// that way
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mapping<T> {
+    // In the order of the symbols in the game's definition
+    pub meanings: Vec<T>,
+    pub score: u32,
+}
+
+// Every order of 0..count, starting with 0..count itself
+fn permutations(count: usize) -> Vec<Vec<usize>> {
+    if count == 0 {
+        return vec![Vec::new()];
+    }
+    (0..count)
+        .flat_map(|first| {
+            permutations(count - 1).into_iter().map(move |rest| {
+                let mut permutation = vec![first];
+                permutation.extend(
+                    rest.iter()
+                        .map(|&index| index + usize::from(index >= first)),
+                );
+                permutation
+            })
+        })
+        .collect()
+}
+
+impl Game {
+    // Every way of reading our symbols as shapes, scoring the most first.
+    // Mappings scoring as much stay in the order of `permutations`.
+    pub fn shape_mappings(&self, input: &str) -> Vec<Mapping<usize>> {
+        let rounds = self.shapes_played(input);
+        let mut mappings = permutations(self.shapes.len())
+            .into_iter()
+            .map(|meanings| Mapping {
+                score: rounds
+                    .iter()
+                    .map(|&(opponent, symbol)| u32::from(self.score(opponent, meanings[symbol])))
+                    .sum(),
+                meanings,
+            })
+            .collect::<Vec<_>>();
+        mappings.sort_by_key(|mapping| Reverse(mapping.score));
+        mappings
+    }
+
+    // Every way of reading our symbols as outcomes, scoring the most first
+    pub fn outcome_mappings(&self, input: &str) -> Vec<Mapping<Outcome>> {
+        let outcomes = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+        let rounds = self.outcomes_wanted(input);
+        let mut mappings = permutations(outcomes.len())
+            .into_iter()
+            .map(|permutation| {
+                let meanings = permutation
+                    .into_iter()
+                    .map(|index| outcomes[index])
+                    .collect::<Vec<_>>();
+                Mapping {
+                    score: rounds
+                        .iter()
+                        .map(|&(opponent, symbol)| {
+                            let outcome = meanings[symbol as usize];
+                            u32::from(self.score(opponent, self.shape_for(opponent, outcome)))
+                        })
+                        .sum(),
+                    meanings,
+                }
+            })
+            .collect::<Vec<_>>();
+        mappings.sort_by_key(|mapping| Reverse(mapping.score));
+        mappings
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: usize,
+    pub ours: usize,
+    // Ours, the opponent's is the opposite
+    pub outcome: Outcome,
+    // Ours first, then the opponent's
+    pub points: (u16, u16),
+    pub totals: (u32, u32),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Breakdown {
+    pub rounds: Vec<Round>,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+}
+
+impl Game {
+    // Both players' points, as if the opponent scored by the same rules
+    fn breakdown(&self, plays: impl IntoIterator<Item = (usize, usize)>) -> Breakdown {
+        let mut totals = (0, 0);
+        let rounds = plays
+            .into_iter()
+            .map(|(opponent, ours)| {
+                let points = (self.score(opponent, ours), self.score(ours, opponent));
+                totals = (
+                    totals.0 + u32::from(points.0),
+                    totals.1 + u32::from(points.1),
+                );
+                Round {
+                    opponent,
+                    ours,
+                    outcome: self.outcome(opponent, ours),
+                    points,
+                    totals,
+                }
+            })
+            .collect::<Vec<_>>();
+        let count = |outcome| {
+            rounds
+                .iter()
+                .filter(|round| round.outcome == outcome)
+                .count()
+        };
+        Breakdown {
+            wins: count(Outcome::Win),
+            draws: count(Outcome::Draw),
+            losses: count(Outcome::Lose),
+            rounds,
+        }
+    }
+
+    pub fn breakdown_part_1(&self, input: &str) -> Breakdown {
+        self.breakdown(self.shapes_played(input))
+    }
+
+    pub fn breakdown_part_2(&self, input: &str) -> Breakdown {
+        self.breakdown(
+            self.outcomes_wanted(input)
+                .into_iter()
+                .map(|(opponent, outcome)| (opponent, self.shape_for(opponent, outcome))),
+        )
+    }
+}
+
+impl Breakdown {
+    pub fn text(&self, game: &Game) -> String {
+        let mut text = format!(
+            "{:>5}  {:<10}{:<10}{:<9}{:>12}{:>12}{:>12}{:>12}\n",
+            "Round", "Them", "Us", "Outcome", "Our points", "Theirs", "Our total", "Theirs"
+        );
+        for (index, round) in self.rounds.iter().enumerate() {
+            text += &format!(
+                "{:>5}  {:<10}{:<10}{:<9}{:>12}{:>12}{:>12}{:>12}\n",
+                index + 1,
+                game.shapes[round.opponent].name,
+                game.shapes[round.ours].name,
+                format!("{:?}", round.outcome).to_lowercase(),
+                round.points.0,
+                round.points.1,
+                round.totals.0,
+                round.totals.1
+            );
+        }
+        let (ours, theirs) = self.rounds.last().map_or((0, 0), |round| round.totals);
+        text + &format!(
+            "Won {}, drew {} and lost {}, scoring {} to {}\n",
+            self.wins, self.draws, self.losses, ours, theirs
+        )
+    }
+
+    // Without a header, so both parts can go in one file
+    pub fn csv(&self, game: &Game, part: u8) -> String {
+        self.rounds
+            .iter()
+            .enumerate()
+            .map(|(index, round)| {
+                csv::row(&[
+                    part.to_string(),
+                    (index + 1).to_string(),
+                    game.shapes[round.opponent].name.clone(),
+                    game.shapes[round.ours].name.clone(),
+                    format!("{:?}", round.outcome).to_lowercase(),
+                    round.points.0.to_string(),
+                    round.points.1.to_string(),
+                    round.totals.0.to_string(),
+                    round.totals.1.to_string(),
+                ]) + "\n"
+            })
+            .collect()
+    }
+}
+
+pub const BREAKDOWN_CSV_HEADER: &str =
+    "part,round,opponent,ours,outcome,our_points,their_points,our_total,their_total\n";
+
+// SplitMix64, which is plenty for simulations and the same on every platform
+pub struct Random(u64);
+
+impl Random {
+    pub fn new(seed: u64) -> Random {
+        Random(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    // In [0, 1)
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    // An index picked with a chance in proportion to its weight
+    pub fn choose(&mut self, weights: &[f64]) -> usize {
+        let mut target = self.next_f64() * weights.iter().sum::<f64>();
+        for (index, weight) in weights.iter().enumerate() {
+            if target < *weight {
+                return index;
+            }
+            target -= weight;
+        }
+        weights.len() - 1
+    }
+}
+
+// How a simulated opponent picks its shapes
+#[derive(Clone, Debug, PartialEq)]
+pub enum Opponent {
+    Uniform,
+    // A weight per shape, in the order of the definition
+    Biased(Vec<f64>),
+    // The shape that won the previous round, or the opponent's again after a
+    // draw, and any shape in the first round
+    RepeatLastWinner,
+}
+
+// "uniform", "biased:5,3,2" or "repeat-last-winner"
+impl FromStr for Opponent {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Opponent, String> {
+        match text.split_once(':') {
+            None if text == "uniform" => Ok(Opponent::Uniform),
+            None if text == "repeat-last-winner" => Ok(Opponent::RepeatLastWinner),
+            Some(("biased", weights)) => {
+                let weights = weights
+                    .split(',')
+                    .map(|weight| weight.parse::<f64>())
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| format!("Expected weights like 5,3,2, found '{}'", weights))?;
+                if weights
+                    .iter()
+                    .any(|weight| !weight.is_finite() || *weight < 0.0)
+                    || weights.iter().sum::<f64>() <= 0.0
+                {
+                    return Err("Expected weights of 0 or more, and not all 0".to_string());
+                }
+                Ok(Opponent::Biased(weights))
+            }
+            _ => Err(format!(
+                "Expected uniform, biased:<weights> or repeat-last-winner, found '{}'",
+                text
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Opponent {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Opponent::Uniform => write!(formatter, "uniform"),
+            Opponent::Biased(weights) => {
+                let weights = weights
+                    .iter()
+                    .map(|weight| weight.to_string())
+                    .collect::<Vec<_>>();
+                write!(formatter, "biased:{}", weights.join(","))
+            }
+            Opponent::RepeatLastWinner => write!(formatter, "repeat-last-winner"),
+        }
+    }
+}
+
+impl Opponent {
+    // The chance of each shape next, given the opponent's and our shape in
+    // the previous round
+    pub fn weights(&self, game: &Game, previous: Option<(usize, usize)>) -> Vec<f64> {
+        let count = game.shapes.len();
+        match (self, previous) {
+            (Opponent::Biased(weights), _) => weights.clone(),
+            (Opponent::RepeatLastWinner, Some((opponent, ours))) => {
+                let winner = match game.outcome(opponent, ours) {
+                    Outcome::Win => ours,
+                    _ => opponent,
+                };
+                (0..count)
+                    .map(|shape| if shape == winner { 1.0 } else { 0.0 })
+                    .collect()
+            }
+            _ => vec![1.0; count],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Summary {
+    pub mean: f64,
+    pub variance: f64,
+}
+
+impl Summary {
+    fn of(scores: &[u32]) -> Summary {
+        let count = scores.len() as f64;
+        let mean = scores.iter().map(|&score| f64::from(score)).sum::<f64>() / count;
+        let squares = scores
+            .iter()
+            .map(|&score| (f64::from(score) - mean).powi(2))
+            .sum::<f64>();
+        Summary {
+            mean,
+            // Of a sample
+            variance: if scores.len() > 1 {
+                squares / (count - 1.0)
+            } else {
+                0.0
+            },
+        }
+    }
+}
+
+// The scores of a game, following the guide or adapting to the opponent
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tournament {
+    pub games: usize,
+    pub rounds: usize,
+    pub guide: Summary,
+    pub adaptive: Summary,
+}
+
+impl Game {
+    // The shape scoring the most on average against those weights
+    pub fn best_response(&self, weights: &[f64]) -> usize {
+        let expected = |ours| {
+            weights
+                .iter()
+                .enumerate()
+                .map(|(opponent, weight)| weight * f64::from(self.score(opponent, ours)))
+                .sum::<f64>()
+        };
+        (1..self.shapes.len()).fold(0, |best, ours| {
+            if expected(ours) > expected(best) {
+                ours
+            } else {
+                best
+            }
+        })
+    }
+
+    fn play(
+        &self,
+        opponent: &Opponent,
+        rounds: usize,
+        random: &mut Random,
+        mut strategy: impl FnMut(usize, &[f64]) -> usize,
+    ) -> u32 {
+        let mut previous = None;
+        let mut score = 0;
+        for round in 0..rounds {
+            let weights = opponent.weights(self, previous);
+            let ours = strategy(round, &weights);
+            let theirs = random.choose(&weights);
+            score += u32::from(self.score(theirs, ours));
+            previous = Some((theirs, ours));
+        }
+        score
+    }
+
+    // Plays the guide's shapes, ignoring its opponent column, against
+    // `games` games of the opponent. The adaptive strategy knows how the
+    // opponent picks and plays the best response every round. Both see the
+    // same random numbers.
+    pub fn simulate(
+        &self,
+        input: &str,
+        opponent: &Opponent,
+        games: usize,
+        seed: u64,
+    ) -> Tournament {
+        if let Opponent::Biased(weights) = opponent {
+            if weights.len() != self.shapes.len() {
+                panic!(
+                    "Expected {} opponent weights, found {}",
+                    self.shapes.len(),
+                    weights.len()
+                );
+            }
+        }
+        let guide = self
+            .shapes_played(input)
+            .into_iter()
+            .map(|(_, ours)| ours)
+            .collect::<Vec<_>>();
+        let scores = |strategy: &dyn Fn(usize, &[f64]) -> usize| {
+            let mut random = Random::new(seed);
+            (0..games.max(1))
+                .map(|_| self.play(opponent, guide.len(), &mut random, strategy))
+                .collect::<Vec<_>>()
+        };
+        Tournament {
+            games: games.max(1),
+            rounds: guide.len(),
+            guide: Summary::of(&scores(&|round, _| guide[round])),
+            adaptive: Summary::of(&scores(&|_, weights| self.best_response(weights))),
+        }
+    }
+}
+
+impl fmt::Display for Tournament {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            formatter,
+            "{:<10}{:>14}{:>14}{:>14}",
+            "Strategy", "Mean score", "Variance", "Per round"
+        )?;
+        for (name, summary) in [("guide", self.guide), ("adaptive", self.adaptive)] {
+            writeln!(
+                formatter,
+                "{:<10}{:>14.2}{:>14.2}{:>14.3}",
+                name,
+                summary.mean,
+                summary.variance,
+                summary.mean / self.rounds.max(1) as f64
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for Game {
+    fn default() -> Game {
+        Game::parse(ROCK_PAPER_SCISSORS).unwrap()
+    }
+}
+
+pub fn part_1(input: &str) -> u32 {
+    Game::default().part_1(input)
+}
+
+pub fn part_2(input: &str) -> u32 {
+    Game::default().part_2(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shapes_played_should_split_on_newline_and_map_to_shapes() {
+        let test_input = "A X\nB Y\nC Z\n\n";
+
+        // Rock, Paper and Scissors in the order of the definition
+        assert_eq!(
+            Game::default().shapes_played(test_input),
+            vec![(0, 0), (1, 1), (2, 2)]
+        );
+    }
+
+    #[test]
+    fn part_1_should_return_the_score_from_following_the_strategy() {
+        let test_input = "A Y\nB X\nC Z\n\n";
+
+        assert_eq!(part_1(test_input), 15);
+    }
+
+    #[test]
+    fn part_2_should_return_the_score_from_following_the_strategy() {
+        let test_input = "A Y\nB X\nC Z\n\n";
+
+        assert_eq!(part_2(test_input), 12);
+    }
+
+    const ROCK_PAPER_SCISSORS_LIZARD_SPOCK: &str =
+        include_str!("../games/rock_paper_scissors_lizard_spock.txt");
+
+    #[test]
+    fn games_should_work_with_any_odd_number_of_shapes() {
+        let game = Game::parse(ROCK_PAPER_SCISSORS_LIZARD_SPOCK).unwrap();
+
+        // Lizard poisons Spock, Spock smashes Scissors
+        assert_eq!(game.outcome(1, 3), Outcome::Win);
+        assert_eq!(game.outcome(1, 4), Outcome::Lose);
+        assert_eq!(game.part_1("A X\nD V\nC V\n"), 9 + 7 + 1);
+        // Paper beats Rock and is worth more than Spock, and Lizard loses to
+        // Scissors and is worth more than Paper
+        assert_eq!(game.part_2("A Z\nE X\n"), 9 + 4);
+    }
+
+    #[test]
+    fn game_parse_should_reject_an_even_number_of_shapes() {
+        let definition = ROCK_PAPER_SCISSORS.replace("shape Scissors C Z 3\n", "");
+
+        assert_eq!(
+            Game::parse(&definition).unwrap_err().to_string(),
+            "Line 6, column 1: Expected an odd number of shapes, at least 3, found 2"
+        );
+    }
+
+    #[test]
+    fn game_parse_should_reject_too_many_shapes() {
+        let definition = (0..9)
+            .map(|shape| format!("shape S{0} A{0} X{0} 1\n", shape))
+            .collect::<String>()
+            + "outcome lose L 0\noutcome draw D 3\noutcome win W 6\n";
+
+        assert_eq!(
+            Game::parse(&definition).unwrap_err().to_string(),
+            "Line 13, column 1: Expected at most 7 shapes, found 9"
+        );
+    }
+
+    #[test]
+    fn game_parse_should_reject_symbols_used_twice() {
+        let error = |definition: &str| Game::parse(definition).unwrap_err().to_string();
+
+        assert_eq!(
+            error(&ROCK_PAPER_SCISSORS.replace("Paper B Y", "Paper B X")),
+            "Line 2, column 15: Our 'X' can't be told apart from Rock's 'X'"
+        );
+        assert_eq!(
+            error(&ROCK_PAPER_SCISSORS.replace("Scissors C", "Scissors AC")),
+            "Line 3, column 16: The opponent's 'AC' can't be told apart from Rock's 'A'"
+        );
+        assert_eq!(
+            error(&ROCK_PAPER_SCISSORS.replace("win Z", "win Y")),
+            "Line 6, column 13: Our 'Y' can't be told apart from Draw's 'Y'"
+        );
+        assert_eq!(
+            error(&ROCK_PAPER_SCISSORS.replace("Scissors", "Rock")),
+            "Line 3, column 1: Rock is defined twice"
+        );
+    }
+
+    #[test]
+    fn scores_should_be_changeable() {
+        let mut game = Game::default();
+        game.set_values("10,20,30").unwrap();
+        game.set_points(Outcome::Draw, 0);
+
+        // Paper beats Rock, Rock loses to Paper, Scissors draws
+        assert_eq!(game.part_1("A Y\nB X\nC Z\n"), (20 + 6) + 10 + 30);
+        assert_eq!(
+            game.set_values("1,2").unwrap_err(),
+            "Expected 3 shape values, found 2"
+        );
+        assert_eq!(
+            game.set_values("1,x,3").unwrap_err(),
+            "Line 1, column 3: Expected a number, found 'x,3'"
+        );
+    }
+
+    #[test]
+    fn permutations_should_list_every_order_once() {
+        assert_eq!(
+            permutations(3),
+            vec![
+                vec![0, 1, 2],
+                vec![0, 2, 1],
+                vec![1, 0, 2],
+                vec![1, 2, 0],
+                vec![2, 0, 1],
+                vec![2, 1, 0]
+            ]
+        );
+        assert_eq!(permutations(5).len(), 120);
+    }
+
+    #[test]
+    fn mappings_should_be_sorted_by_score() {
+        let game = Game::default();
+        let test_input = "A Y\nB X\nC Z\n";
+        let shapes = game.shape_mappings(test_input);
+        let outcomes = game.outcome_mappings(test_input);
+
+        // Always winning: Y is Paper, X is Scissors and Z is Rock
+        assert_eq!(
+            shapes[0],
+            Mapping {
+                meanings: vec![2, 1, 0],
+                score: 8 + 9 + 7
+            }
+        );
+        assert!(shapes.contains(&Mapping {
+            meanings: vec![0, 1, 2],
+            score: 15
+        }));
+        assert_eq!(shapes.len(), 6);
+        // The puzzle's reading scores the least
+        assert_eq!(
+            outcomes.last(),
+            Some(&Mapping {
+                meanings: vec![Outcome::Lose, Outcome::Draw, Outcome::Win],
+                score: 4 + 1 + 7
+            })
+        );
+        assert_eq!(outcomes.len(), 6);
+    }
+
+    #[test]
+    fn breakdown_should_score_both_players() {
+        let game = Game::default();
+        let breakdown = game.breakdown_part_1("A Y\nB X\nC Z\n");
+
+        assert_eq!(
+            (breakdown.wins, breakdown.draws, breakdown.losses),
+            (1, 1, 1)
+        );
+        assert_eq!(
+            breakdown.rounds[1],
+            Round {
+                opponent: 1,
+                ours: 0,
+                outcome: Outcome::Lose,
+                points: (1, 8),
+                totals: (9, 9)
+            }
+        );
+        assert_eq!(breakdown.rounds[2].totals, (15, 15));
+        assert_eq!(
+            breakdown.csv(&game, 1).lines().next(),
+            Some("1,1,Rock,Paper,win,8,1,8,1")
+        );
+        assert_eq!(
+            game.breakdown_part_2("A Y\nB X\nC Z\n").rounds[2].totals,
+            (12, 15)
+        );
+    }
+
+    #[test]
+    fn breakdown_csv_should_quote_shape_names() {
+        let game = Game::parse(
+            &ROCK_PAPER_SCISSORS
+                .replace("Rock", "Rock,Hard")
+                .replace("Paper", "\"Paper\""),
+        )
+        .unwrap();
+
+        assert_eq!(
+            game.breakdown_part_1("A Y\n").csv(&game, 1),
+            "1,1,\"Rock,Hard\",\"\"\"Paper\"\"\",win,8,1,8,1\n"
+        );
+    }
+
+    #[test]
+    fn opponents_should_parse_from_text() {
+        assert_eq!("uniform".parse(), Ok(Opponent::Uniform));
+        assert_eq!(
+            "biased:5,3,2".parse(),
+            Ok(Opponent::Biased(vec![5.0, 3.0, 2.0]))
+        );
+        assert_eq!(Opponent::Biased(vec![5.0, 0.5]).to_string(), "biased:5,0.5");
+        assert_eq!(
+            "random".parse::<Opponent>(),
+            Err(
+                "Expected uniform, biased:<weights> or repeat-last-winner, found 'random'"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn simulate_should_score_the_guide_and_the_best_response() {
+        let game = Game::default();
+        // Always Rock against an opponent always playing Rock
+        let tournament = game.simulate("A X\nB X\nC X\n", &"biased:1,0,0".parse().unwrap(), 10, 1);
+
+        assert_eq!(
+            tournament.guide,
+            Summary {
+                mean: 12.0,
+                variance: 0.0
+            }
+        );
+        assert_eq!(
+            tournament.adaptive,
+            Summary {
+                mean: 24.0,
+                variance: 0.0
+            }
+        );
+
+        // Against a uniform opponent Rock scores 1 + (0 + 3 + 6) / 3 a round
+        let uniform = game.simulate(&"A X\n".repeat(10), &Opponent::Uniform, 2000, 2022);
+        assert!((uniform.guide.mean - 40.0).abs() < 1.0);
+        assert_eq!(
+            uniform,
+            game.simulate(&"A X\n".repeat(10), &Opponent::Uniform, 2000, 2022)
+        );
+    }
+
+    #[test]
+    fn repeat_last_winner_should_be_beaten_after_the_first_round() {
+        let game = Game::default();
+        let tournament = game.simulate(&"A X\n".repeat(5), &Opponent::RepeatLastWinner, 500, 7);
+
+        // Scissors first, which scores 3 + 0, 3 or 6, then a win worth at
+        // least 7 every round
+        assert!(tournament.adaptive.mean >= 3.0 + 3.0 + 4.0 * 7.0);
+        assert!(tournament.adaptive.mean > tournament.guide.mean);
+    }
+}
@@ -1,6 +1,11 @@
 use itertools::Itertools;
 use std::collections::HashMap;
 
+#[cfg(feature = "embed-input")]
+pub const INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embed-input"))]
+pub const INPUT: Option<&str> = None;
+
 fn get_priority_map() -> HashMap<char, usize> {
     let mut priorities: HashMap<char, usize> = HashMap::new();
     let mut letters = ('a'..='z').collect::<Vec<_>>();
@@ -11,7 +16,7 @@ fn get_priority_map() -> HashMap<char, usize> {
     priorities
 }
 
-fn part_1(input: &str) -> u16 {
+pub fn part_1(input: &str) -> u16 {
     let priorities = get_priority_map();
     input
         .split('\n')
@@ -28,7 +33,7 @@ fn part_1(input: &str) -> u16 {
         .sum()
 }
 
-fn part_2(input: &str) -> usize {
+pub fn part_2(input: &str) -> usize {
     let priorities = get_priority_map();
     let mut rucksacks = input
         .split('\n')
@@ -52,19 +57,6 @@ fn part_2(input: &str) -> usize {
     priorities_sum
 }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    let path = &args[1];
-    let input = std::fs::read_to_string(path)
-        .unwrap_or_else(|error| panic!("No file found at: '{}'.\n{}", path, error));
-
-    let part_1_result = part_1(&input);
-    println!("Day 3 Part 1: {}", part_1_result);
-
-    let part_2_result = part_2(&input);
-    println!("Day 3 Part 2: {}", part_2_result);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
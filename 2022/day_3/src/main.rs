@@ -0,0 +1,17 @@
+use y2022_day_3::{part_1, part_2, INPUT};
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    let input = match (args.get(1), INPUT) {
+        (Some(path), _) => std::fs::read_to_string(path)
+            .unwrap_or_else(|error| panic!("No file found at: '{}'.\n{}", path, error)),
+        (None, Some(input)) => input.to_string(),
+        (None, None) => panic!("Expected the path to the puzzle input"),
+    };
+
+    let part_1_result = part_1(&input);
+    println!("Day 3 Part 1: {}", part_1_result);
+
+    let part_2_result = part_2(&input);
+    println!("Day 3 Part 2: {}", part_2_result);
+}
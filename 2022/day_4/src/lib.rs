@@ -0,0 +1,63 @@
+use common::parse::{parse, Parser};
+
+#[cfg(feature = "embed-input")]
+pub const INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embed-input"))]
+pub const INPUT: Option<&str> = None;
+
+type Assignment = (u16, u16);
+
+// Lines like '2-4,6-8'
+fn parse_pairs(input: &str) -> Vec<(Assignment, Assignment)> {
+    let assignment = |parser: &mut Parser| -> common::parse::Result<Assignment> {
+        let start = parser.number()?;
+        parser.literal("-")?;
+        Ok((start, parser.number()?))
+    };
+    parse(input, |parser| {
+        parser.lines(|parser| {
+            let left = assignment(parser)?;
+            parser.literal(",")?;
+            Ok((left, assignment(parser)?))
+        })
+    })
+    .unwrap_or_else(|error| panic!("{}", error))
+}
+
+pub fn part_1(input: &str) -> u16 {
+    parse_pairs(input)
+        .iter()
+        .filter(|((left_start, left_end), (right_start, right_end))| {
+            (left_start <= right_start && left_end >= right_end)
+                || (right_start <= left_start && right_end >= left_end)
+        })
+        .count() as u16
+}
+
+pub fn part_2(input: &str) -> u16 {
+    parse_pairs(input)
+        .iter()
+        .filter(|((left_start, left_end), (right_start, right_end))| {
+            left_start <= right_end && left_end >= right_start
+        })
+        .count() as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_should_find_the_assignments_that_are_fully_contained() {
+        let test_input = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n\n";
+
+        assert_eq!(part_1(test_input), 2);
+    }
+
+    #[test]
+    fn part_2_should_find_the_assignments_that_overlap() {
+        let test_input = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n\n";
+
+        assert_eq!(part_2(test_input), 4);
+    }
+}
@@ -0,0 +1,334 @@
+use common::{
+    explore::{Command, Explore},
+    parse::{parse, Parser},
+    trace::{Trace, Value},
+};
+
+#[cfg(feature = "embed-input")]
+pub const INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embed-input"))]
+pub const INPUT: Option<&str> = None;
+
+fn parse_stack_line(line: &str) -> Vec<(usize, char)> {
+    line.chars()
+        .collect::<Vec<_>>()
+        .chunks(4)
+        .enumerate()
+        .filter_map(|(index, chars)| {
+            let characters = chars
+                .iter()
+                .filter(|character| character.is_alphabetic())
+                .collect::<Vec<&char>>();
+            if characters.is_empty() {
+                None
+            } else {
+                Some((index, *characters[0]))
+            }
+        })
+        .collect::<_>()
+}
+
+// Lines like 'move 1 from 2 to 1'
+fn parse_instruction_line(parser: &mut Parser) -> common::parse::Result<[usize; 3]> {
+    parser.literal("move ")?;
+    let count = parser.number()?;
+    parser.literal(" from ")?;
+    let from = parser.number()?;
+    parser.literal(" to ")?;
+    Ok([count, from, parser.number()?])
+}
+
+fn get_stack_count(stack_lines: &str) -> usize {
+    stack_lines
+        .chars()
+        .filter_map(|character| character.to_digit(10))
+        .count()
+}
+
+fn stack_lines_to_map(mut lines: Vec<(usize, char)>, stack_count: usize) -> Vec<Vec<char>> {
+    let mut result: Vec<Vec<char>> = Vec::new();
+    for _ in 0..stack_count {
+        result.push(Vec::new());
+    }
+
+    for (index, character) in lines.iter_mut().rev() {
+        result[*index].push(*character);
+    }
+
+    result
+}
+
+// The lines of the drawing of the stacks, a blank line and then the
+// instructions
+fn split_input(input: &str) -> (Vec<&str>, Vec<[usize; 3]>) {
+    parse(input, |parser| {
+        let initial_stacks = parser.lines(Parser::rest_of_line)?;
+        if parser.at_end() {
+            return Err(parser.error(
+                "Expected the stacks and the instructions to be separated by a blank line",
+            ));
+        }
+        parser.line_end()?;
+        Ok((initial_stacks, parser.lines(parse_instruction_line)?))
+    })
+    .unwrap_or_else(|error| panic!("{}", error))
+}
+
+fn parse_input(input: &str) -> (Vec<Vec<char>>, Vec<[usize; 3]>) {
+    let (initial_stacks, instructions) = split_input(input);
+
+    // The last line of the drawing numbers the stacks
+    let stack_count = get_stack_count(initial_stacks.last().unwrap_or(&""));
+    let stack_lines = initial_stacks
+        .into_iter()
+        .flat_map(parse_stack_line)
+        .collect::<Vec<_>>();
+    (stack_lines_to_map(stack_lines, stack_count), instructions)
+}
+
+fn render_stacks(stack_map: &[Vec<char>]) -> String {
+    let height = stack_map.iter().map(Vec::len).max().unwrap_or(0);
+    let mut lines = (0..height)
+        .rev()
+        .map(|level| {
+            stack_map
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(character) => format!("[{}]", character),
+                    None => "   ".to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>();
+    lines.push(
+        (1..=stack_map.len())
+            .map(|number| format!(" {} ", number))
+            .collect::<Vec<_>>()
+            .join(" "),
+    );
+    lines.join("\n")
+}
+
+// The stacks are listed bottom to top, after the instruction was carried out
+fn stacks_state(stack_map: &[Vec<char>], instruction: Option<[usize; 3]>) -> Value {
+    Value::Object(vec![
+        (
+            "instruction",
+            instruction.map_or(Value::Null, |instruction| {
+                Value::List(
+                    instruction
+                        .iter()
+                        .map(|&number| Value::Number(number as i64))
+                        .collect(),
+                )
+            }),
+        ),
+        (
+            "stacks",
+            Value::List(
+                stack_map
+                    .iter()
+                    .map(|stack| Value::Text(stack.iter().collect()))
+                    .collect(),
+            ),
+        ),
+    ])
+}
+
+pub fn part_1_traced(input: &str, trace: &mut impl Trace) -> String {
+    let (mut stack_map, instructions) = parse_input(input);
+    trace.step(|| stacks_state(&stack_map, None));
+    instructions.into_iter().for_each(|instruction| {
+        let [move_count, from_stack, to_stack] = instruction;
+        for _ in 0..move_count {
+            if let Some(element_to_move) = stack_map[from_stack - 1].pop() {
+                stack_map[to_stack - 1].push(element_to_move);
+            }
+        }
+        trace.step(|| stacks_state(&stack_map, Some(instruction)));
+    });
+    stack_map
+        .iter()
+        .filter_map(|stack| stack.last())
+        .collect::<String>()
+}
+
+pub fn part_1(input: &str) -> String {
+    part_1_traced(input, &mut ())
+}
+
+pub fn part_2_traced(input: &str, trace: &mut impl Trace) -> String {
+    let (mut stack_map, instructions) = parse_input(input);
+    trace.step(|| stacks_state(&stack_map, None));
+    instructions.into_iter().for_each(|instruction| {
+        let [move_count, from_stack, to_stack] = instruction;
+        let stack_length = stack_map[from_stack - 1].len();
+        let mut element_to_append = stack_map[from_stack - 1]
+            .drain(stack_length - move_count..)
+            .collect();
+        stack_map[to_stack - 1].append(&mut element_to_append);
+        trace.step(|| stacks_state(&stack_map, Some(instruction)));
+    });
+    stack_map
+        .iter()
+        .filter_map(|stack| stack.last())
+        .collect::<String>()
+}
+
+pub fn part_2(input: &str) -> String {
+    part_2_traced(input, &mut ())
+}
+
+pub struct Cargo {
+    stack_map: Vec<Vec<char>>,
+    instructions: Vec<[usize; 3]>,
+}
+
+impl Cargo {
+    pub fn new(input: &str) -> Cargo {
+        let (stack_map, instructions) = parse_input(input);
+        Cargo {
+            stack_map,
+            instructions,
+        }
+    }
+}
+
+impl Explore for Cargo {
+    fn commands(&self) -> &'static [Command] {
+        &[
+            Command {
+                usage: "show stacks",
+                description: "Draw the starting stacks of crates",
+            },
+            Command {
+                usage: "show instructions",
+                description: "List the rearrangement procedure",
+            },
+        ]
+    }
+
+    fn execute(&mut self, command: &str, arguments: &[&str]) -> Option<Result<String, String>> {
+        match (command, arguments) {
+            ("show", ["stacks"]) => Some(Ok(render_stacks(&self.stack_map))),
+            ("show", ["instructions"]) => Some(Ok(self
+                .instructions
+                .iter()
+                .map(|[move_count, from_stack, to_stack]| {
+                    format!("move {} from {} to {}", move_count, from_stack, to_stack)
+                })
+                .collect::<Vec<_>>()
+                .join("\n"))),
+            ("show", _) => Some(Err(
+                "Expected 'show stacks' or 'show instructions'".to_string()
+            )),
+            _ => None,
+        }
+    }
+}
+
+// Carries out the instructions `factor` times, every other time undoing them by
+// moving the crates back in reverse order, so no stack runs out of crates.
+// With an odd factor the answers stay the same.
+pub fn scale_input(input: &str, factor: usize) -> String {
+    let (stacks, instructions) = split_input(input);
+
+    let mut scaled = format!("{}\n\n", stacks.join("\n"));
+    for repetition in 0..factor {
+        if repetition % 2 == 0 {
+            for [move_count, from_stack, to_stack] in &instructions {
+                scaled += &format!("move {} from {} to {}\n", move_count, from_stack, to_stack);
+            }
+        } else {
+            for [move_count, from_stack, to_stack] in instructions.iter().rev() {
+                scaled += &format!("move {} from {} to {}\n", move_count, to_stack, from_stack);
+            }
+        }
+    }
+    scaled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_stack_line_should_return_a_vec_with_chars_and_their_indices() {
+        let line = "[Z] [M] [P]";
+        assert_eq!(parse_stack_line(line), vec![(0, 'Z'), (1, 'M'), (2, 'P')]);
+        let line = "    [D]    ";
+        assert_eq!(parse_stack_line(line), vec![(1, 'D')]);
+    }
+
+    #[test]
+    fn parse_stack_line_should_ignore_the_line_with_column_numbers() {
+        let line = "1   2   3   4   5   6   7   8   9 ";
+        assert_eq!(parse_stack_line(line), vec![]);
+    }
+
+    #[test]
+    fn stack_lines_to_map_should_return_a_two_dimensional_vec_of_chars() {
+        let input = vec![(0, 'A'), (1, 'B')];
+        assert_eq!(stack_lines_to_map(input, 2), vec![vec!['A'], vec!['B']]);
+        let input = vec![(0, 'A'), (2, 'B')];
+        assert_eq!(
+            stack_lines_to_map(input, 3),
+            vec![vec!['A'], vec![], vec!['B']]
+        );
+    }
+
+    #[test]
+    fn get_stack_count_should_return_the_amount_of_stacks() {
+        let stack_lines = "    [A] [B]\n 1   2   3 \n";
+        assert_eq!(get_stack_count(stack_lines), 3);
+    }
+
+    #[test]
+    fn render_stacks_should_draw_the_stacks_like_the_input() {
+        let stack_map = vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']];
+        assert_eq!(
+            render_stacks(&stack_map),
+            "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 "
+        );
+    }
+
+    #[test]
+    fn part_1_traced_should_record_the_stacks_after_every_instruction() {
+        let input = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\n";
+        let mut states = Vec::new();
+        part_1_traced(input, &mut states);
+
+        assert_eq!(states.len(), 3);
+        assert_eq!(
+            states[2].to_string(),
+            "{\"instruction\":[3,1,3],\"stacks\":[\"\",\"MC\",\"PDNZ\"]}"
+        );
+    }
+
+    #[test]
+    fn scale_input_should_keep_the_answers_for_an_odd_factor() {
+        let input = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\nmove 2 from 2 to 1\nmove 1 from 1 to 2\n";
+        let scaled = scale_input(input, 3);
+
+        assert_eq!(
+            scaled
+                .lines()
+                .filter(|line| line.starts_with("move"))
+                .count(),
+            12
+        );
+        assert_eq!(part_1(&scaled), "CMZ");
+        assert_eq!(part_2(&scaled), "MCD");
+    }
+
+    #[test]
+    fn scale_input_should_accept_crlf() {
+        let input = "    [D]    \r\n[N] [C]    \r\n[Z] [M] [P]\r\n 1   2   3 \r\n\r\nmove 1 from 2 to 1\r\nmove 3 from 1 to 3\r\nmove 2 from 2 to 1\r\nmove 1 from 1 to 2\r\n";
+        let scaled = scale_input(input, 3);
+
+        assert!(scaled.starts_with("    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1"));
+        assert_eq!(part_1(&scaled), "CMZ");
+        assert_eq!(part_2(&scaled), "MCD");
+    }
+}
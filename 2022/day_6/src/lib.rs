@@ -0,0 +1,141 @@
+use std::collections::HashSet;
+
+#[cfg(feature = "embed-input")]
+pub const INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embed-input"))]
+pub const INPUT: Option<&str> = None;
+
+fn find_index_of_first_set_of_unique_chars(iter: &[char], set_size: usize) -> usize {
+    iter.windows(set_size)
+        .enumerate()
+        .find_map(|(index, window)| {
+            let mut unique = HashSet::new();
+            if window.iter().all(|value| unique.insert(value)) {
+                Some(index + set_size)
+            } else {
+                None
+            }
+        })
+        .unwrap()
+}
+
+pub const PACKET_WINDOW: usize = 4;
+pub const MESSAGE_WINDOW: usize = 14;
+
+pub fn find_marker(input: &str, window_size: usize) -> usize {
+    let characters = input.chars().collect::<Vec<_>>();
+    find_index_of_first_set_of_unique_chars(&characters, window_size)
+}
+
+pub fn part_1(input: &str) -> usize {
+    find_marker(input, PACKET_WINDOW)
+}
+
+pub fn part_2(input: &str) -> usize {
+    find_marker(input, MESSAGE_WINDOW)
+}
+
+// Keeps the letters of the window as bits, flipping a letter's bit as it
+// enters and leaves the window. A letter that's in the window twice cancels
+// itself out, so the window is a marker when every one of its bits is set.
+pub fn find_marker_bitmask(input: &str, window_size: usize) -> usize {
+    let bit = |byte: u8| {
+        assert!(
+            byte.is_ascii_lowercase(),
+            "Unexpected character: '{}'",
+            byte as char
+        );
+        1u32 << (byte - b'a')
+    };
+    let bytes = input.trim_end().as_bytes();
+    let mut letters = 0;
+    for (index, &byte) in bytes.iter().enumerate() {
+        letters ^= bit(byte);
+        if index >= window_size {
+            letters ^= bit(bytes[index - window_size]);
+        }
+        if letters.count_ones() as usize == window_size {
+            return index + 1;
+        }
+    }
+    panic!("No marker of {} different characters found", window_size)
+}
+
+// Puts the input's first character in front of it until the stream is
+// `factor` times as long. Every window that includes one of those repeats a
+// character, so the markers move to the end of the scaled stream.
+pub fn scale_input(input: &str, factor: usize) -> String {
+    let input = input.trim_end();
+    let first = input.chars().next().unwrap_or('a');
+    let filler = first
+        .to_string()
+        .repeat(factor.saturating_sub(1) * input.len());
+    filler + input + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_should_return_the_1_index_of_the_first_marker_found() {
+        let input = "bvwbjplbgvbhsrlpgdmjqwftvncz";
+        assert_eq!(part_1(input), 5);
+
+        let input = "nppdvjthqldpwncqszvftbrmjlhg";
+        assert_eq!(part_1(input), 6);
+
+        let input = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg";
+        assert_eq!(part_1(input), 10);
+
+        let input = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
+        assert_eq!(part_1(input), 11);
+    }
+
+    #[test]
+    fn part_2_should_return_the_1_index_of_the_first_marker_found() {
+        let input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+        assert_eq!(part_2(input), 19);
+
+        let input = "bvwbjplbgvbhsrlpgdmjqwftvncz";
+        assert_eq!(part_2(input), 23);
+
+        let input = "nppdvjthqldpwncqszvftbrmjlhg";
+        assert_eq!(part_2(input), 23);
+
+        let input = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg";
+        assert_eq!(part_2(input), 29);
+
+        let input = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
+        assert_eq!(part_2(input), 26);
+    }
+
+    #[test]
+    fn find_marker_bitmask_should_find_the_same_markers() {
+        for input in [
+            "mjqjpqmgbljsphdztnvjfqwrcgsmlb",
+            "bvwbjplbgvbhsrlpgdmjqwftvncz",
+            "nppdvjthqldpwncqszvftbrmjlhg",
+            "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg",
+            "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw",
+        ] {
+            for window in [PACKET_WINDOW, MESSAGE_WINDOW] {
+                assert_eq!(
+                    find_marker_bitmask(input, window),
+                    find_marker(input, window)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn scale_input_should_move_the_markers_to_the_end() {
+        let input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n";
+
+        assert_eq!(find_marker(&scale_input(input, 1), PACKET_WINDOW), 7);
+        assert_eq!(
+            find_marker(&scale_input(input, 10), PACKET_WINDOW),
+            9 * 30 + 7
+        );
+    }
+}
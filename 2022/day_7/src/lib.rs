@@ -0,0 +1,342 @@
+use common::{
+    explore::{Command, Explore},
+    parse::{parse, Parser},
+};
+use std::collections::HashMap;
+
+#[cfg(feature = "embed-input")]
+pub const INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embed-input"))]
+pub const INPUT: Option<&str> = None;
+
+#[derive(Debug)]
+struct Dir {
+    // size of files directly in this dir
+    size: u32,
+    children: Vec<String>,
+}
+
+fn get_size_of_directory(
+    directory_path: &str,
+    map: &HashMap<String, Dir>,
+    sizes: &mut Vec<(String, u32)>,
+) -> u32 {
+    let mut child_dir_size = 0;
+    let dir = map.get(directory_path).unwrap();
+    for child in dir.children.iter() {
+        child_dir_size +=
+            get_size_of_directory(&(directory_path.to_owned() + "/" + child), map, sizes);
+    }
+
+    let directory_size = dir.size + child_dir_size;
+    sizes.push((directory_path.to_string(), directory_size));
+
+    directory_size
+}
+
+enum Output<'a> {
+    Cd(&'a str),
+    Ls,
+    Dir(&'a str),
+    File(u32),
+}
+
+// A command or a line of what `ls` printed
+fn parse_output_line<'a>(parser: &mut Parser<'a>) -> common::parse::Result<Output<'a>> {
+    if parser.peek("$") {
+        match parser.one_of(&[("$ cd ", true), ("$ ls", false)])? {
+            true => Ok(Output::Cd(parser.rest_of_line()?)),
+            false => Ok(Output::Ls),
+        }
+    } else if parser.peek("dir ") {
+        parser.literal("dir ")?;
+        Ok(Output::Dir(parser.rest_of_line()?))
+    } else {
+        let size = parser.number()?;
+        parser.literal(" ")?;
+        parser.rest_of_line()?;
+        Ok(Output::File(size))
+    }
+}
+
+fn build_dir_map(input: &str) -> HashMap<String, Dir> {
+    let lines = parse(input, |parser| parser.lines(parse_output_line))
+        .unwrap_or_else(|error| panic!("{}", error));
+
+    let mut map = HashMap::<String, Dir>::new();
+    let mut current_path = Vec::new();
+    for line in lines {
+        match line {
+            Output::Cd("..") => {
+                current_path.pop();
+            }
+            Output::Cd(new_directory) => current_path.push(new_directory.to_string()),
+            Output::Ls => {}
+            Output::Dir(directory_name) => known_dir(&mut map, &current_path)
+                .children
+                .push(directory_name.to_string()),
+            Output::File(size) => known_dir(&mut map, &current_path).size += size,
+        }
+    }
+    map
+}
+
+fn known_dir<'a>(map: &'a mut HashMap<String, Dir>, current_path: &[String]) -> &'a mut Dir {
+    map.entry(current_path.join("/")).or_insert_with(|| Dir {
+        size: 0,
+        children: Vec::<_>::new(),
+    })
+}
+
+// The directories of at most 100000, with their paths and sizes
+pub fn small_directories(input: &str) -> Vec<(String, u32)> {
+    let map = build_dir_map(input);
+    let mut sizes = Vec::new();
+    get_size_of_directory("/", &map, &mut sizes);
+
+    sizes
+        .into_iter()
+        .filter(|&(_, directory_size)| directory_size <= 100000)
+        .map(|(key, directory_size)| (key_to_path(&key), directory_size))
+        .collect()
+}
+
+pub fn part_1(input: &str) -> u32 {
+    small_directories(input)
+        .iter()
+        .map(|(_, directory_size)| directory_size)
+        .sum()
+}
+
+pub const TOTAL_SPACE: u32 = 70000000;
+pub const REQUIRED_SPACE: u32 = 30000000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deletion {
+    // None when there's already enough free space and nothing needs deleting
+    pub path: Option<String>,
+    pub size: u32,
+    // How much more space the update needs than is free
+    pub space_needed: u32,
+}
+
+// The smallest directory that frees up enough space for the update
+pub fn choose_directory_to_delete(input: &str, total_space: u32, required_space: u32) -> Deletion {
+    let map = build_dir_map(input);
+    let mut sizes = Vec::new();
+    let total_space_used = get_size_of_directory("/", &map, &mut sizes);
+    let free_space = total_space.saturating_sub(total_space_used);
+    let space_needed = required_space.saturating_sub(free_space);
+    if space_needed == 0 {
+        return Deletion {
+            path: None,
+            size: 0,
+            space_needed,
+        };
+    }
+    let (key, size) = sizes
+        .into_iter()
+        .filter(|&(_, directory_size)| directory_size >= space_needed)
+        .min_by_key(|&(_, directory_size)| directory_size)
+        .expect("No directory frees up enough space for the update");
+
+    Deletion {
+        path: Some(key_to_path(&key)),
+        size,
+        space_needed,
+    }
+}
+
+pub fn find_directory_to_delete(input: &str, total_space: u32, required_space: u32) -> u32 {
+    choose_directory_to_delete(input, total_space, required_space).size
+}
+
+pub fn part_2(input: &str) -> u32 {
+    find_directory_to_delete(input, TOTAL_SPACE, REQUIRED_SPACE)
+}
+
+// Directory keys are the `cd` arguments joined by '/', so the root is "/"
+// and "/a/e" is stored as "//a/e".
+fn path_to_key(path: &str) -> String {
+    let path = path.trim_end_matches('/');
+    if path.is_empty() {
+        "/".to_string()
+    } else {
+        "/".to_string() + path
+    }
+}
+
+// The other way around
+fn key_to_path(key: &str) -> String {
+    match key.strip_prefix('/') {
+        Some("") | None => "/".to_string(),
+        Some(path) => path.to_string(),
+    }
+}
+
+pub struct FileSystem {
+    map: HashMap<String, Dir>,
+}
+
+impl FileSystem {
+    pub fn new(input: &str) -> FileSystem {
+        FileSystem {
+            map: build_dir_map(input),
+        }
+    }
+}
+
+impl Explore for FileSystem {
+    fn commands(&self) -> &'static [Command] {
+        &[
+            Command {
+                usage: "du <path>",
+                description: "Total size of a directory, e.g. 'du /a'",
+            },
+            Command {
+                usage: "ls <path>",
+                description: "Sub-directories and size of the files directly in a directory",
+            },
+        ]
+    }
+
+    fn execute(&mut self, command: &str, arguments: &[&str]) -> Option<Result<String, String>> {
+        if command != "du" && command != "ls" {
+            return None;
+        }
+        let [path] = arguments else {
+            return Some(Err(format!("Usage: {} <path>", command)));
+        };
+        let key = path_to_key(path);
+        let Some(dir) = self.map.get(&key) else {
+            return Some(Err(format!("No such directory: '{}'", path)));
+        };
+
+        if command == "du" {
+            let mut sizes = Vec::new();
+            Some(Ok(
+                get_size_of_directory(&key, &self.map, &mut sizes).to_string()
+            ))
+        } else {
+            let mut lines = dir
+                .children
+                .iter()
+                .map(|child| format!("dir {}", child))
+                .collect::<Vec<_>>();
+            lines.push(format!("{} in files", dir.size));
+            Some(Ok(lines.join("\n")))
+        }
+    }
+}
+
+// Repeats the session `factor` times, each copy in a directory of an earlier
+// copy: copy n goes into copies 2n + 1 and 2n + 2, so the tree gets deeper as
+// well as wider. File sizes are divided by the factor to keep the disk from
+// filling up.
+pub fn scale_input(input: &str, factor: usize) -> String {
+    let copy_path = |mut copy: usize| {
+        let mut names = Vec::new();
+        while copy > 0 {
+            names.push(format!("scaled_{}", copy));
+            copy = (copy - 1) / 2;
+        }
+        names.reverse();
+        names
+    };
+
+    let mut scaled = String::new();
+    // How far below the root the session is, as `build_dir_map` doesn't go
+    // back to the root on `cd /` the copies get there with `cd ..`
+    let mut depth = 0;
+    for copy in 0..factor {
+        let mut listing_root = false;
+        let mut listed_copies = false;
+        for line in input.lines() {
+            if line == "$ cd /" {
+                if scaled.is_empty() {
+                    scaled += "$ cd /\n";
+                }
+                scaled += &"$ cd ..\n".repeat(depth);
+                depth = 0;
+                for name in copy_path(copy) {
+                    scaled += &format!("$ cd {}\n", name);
+                    depth += 1;
+                }
+                listing_root = true;
+                continue;
+            }
+            if line == "$ cd .." {
+                depth -= 1;
+            } else if line.starts_with("$ cd ") {
+                depth += 1;
+            }
+            if line.starts_with("$ cd ") {
+                listing_root = false;
+            }
+
+            match line.split_once(' ') {
+                Some((size, name)) if size.parse::<u32>().is_ok() => {
+                    let size = size.parse::<u32>().unwrap() / factor.max(1) as u32;
+                    scaled += &format!("{} {}\n", size.max(1), name);
+                }
+                _ => scaled += &format!("{}\n", line),
+            }
+
+            if line == "$ ls" && listing_root && !listed_copies {
+                for child in [2 * copy + 1, 2 * copy + 2] {
+                    if child < factor {
+                        scaled += &format!("dir scaled_{}\n", child);
+                    }
+                }
+                listed_copies = true;
+            }
+        }
+    }
+    scaled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_input_should_nest_the_copies_of_the_session() {
+        let input = "$ cd /\n$ ls\ndir a\n300 b.txt\n$ cd a\n$ ls\n30 c.txt\n";
+        let scaled = scale_input(input, 3);
+
+        assert!(scaled.contains("$ cd /\n$ ls\ndir scaled_1\ndir scaled_2\ndir a\n100 b.txt\n"));
+        assert!(scaled.contains("$ cd ..\n$ cd ..\n$ cd scaled_2\n$ ls\ndir a\n"));
+        // The root, both copies and the 3 copies of 'a'
+        assert_eq!(part_1(&scaled), 330 + 110 + 110 + 3 * 10);
+    }
+
+    #[test]
+    fn choose_directory_to_delete_should_name_the_directory() {
+        let input = "$ cd /\n$ ls\ndir a\n300 b.txt\n$ cd a\n$ ls\ndir e\n30 c.txt\n$ cd e\n$ ls\n20 d.txt\n";
+
+        assert_eq!(
+            choose_directory_to_delete(input, 400, 60),
+            Deletion {
+                path: Some("/a/e".to_string()),
+                size: 20,
+                // 350 used of 400 leaves 50 free
+                space_needed: 10
+            }
+        );
+    }
+
+    #[test]
+    fn choose_directory_to_delete_should_delete_nothing_when_enough_space_is_free() {
+        let input = "$ cd /\n$ ls\ndir a\n300 b.txt\n$ cd a\n$ ls\ndir e\n30 c.txt\n$ cd e\n$ ls\n20 d.txt\n";
+
+        assert_eq!(
+            choose_directory_to_delete(input, 400, 1),
+            Deletion {
+                path: None,
+                size: 0,
+                space_needed: 0
+            }
+        );
+        assert_eq!(find_directory_to_delete(input, 400, 50), 0);
+    }
+}
@@ -1,5 +1,10 @@
 use std::collections::HashMap;
 
+#[cfg(feature = "embed-input")]
+pub const INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embed-input"))]
+pub const INPUT: Option<&str> = None;
+
 enum Direction {
     Left,
     Right,
@@ -7,7 +12,7 @@ enum Direction {
     Down,
 }
 
-fn create_map(input: &str) -> HashMap<(usize, usize), u32> {
+pub fn create_map(input: &str) -> HashMap<(usize, usize), u32> {
     HashMap::from_iter(
         input
             .split('\n')
@@ -83,7 +88,7 @@ fn search_in_all_directions_until_edge_or_blocked(
     (distance_left, distance_right, distance_up, distance_down)
 }
 
-fn part_1(input: &str) -> usize {
+pub fn part_1(input: &str) -> usize {
     let map: HashMap<(usize, usize), u32> = create_map(input);
     let mut visible_trees = Vec::<(usize, usize)>::new();
     let upper_limit = ((map.len() as f32).sqrt() - 1.) as usize;
@@ -116,7 +121,7 @@ fn part_1(input: &str) -> usize {
     visible_trees.len()
 }
 
-fn part_2(input: &str) -> usize {
+pub fn part_2(input: &str) -> usize {
     let map: HashMap<(usize, usize), u32> = create_map(input);
     let upper_limit = ((map.len() as f32).sqrt() - 1.) as usize;
     let mut scenic_scores = map
@@ -128,17 +133,92 @@ fn part_2(input: &str) -> usize {
     *scenic_scores.last().unwrap() as usize
 }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    let path = &args[1];
-    let input = std::fs::read_to_string(path)
-        .unwrap_or_else(|error| panic!("No file found at: '{}'.\n{}", path, error));
+// For each tree, how many trees it sees looking back along `heights` and
+// whether it sees past the edge. The stack holds the trees that could still
+// block the view of a later tree, tallest at the bottom, so every tree is
+// pushed and popped once.
+fn look_back(heights: &[u8]) -> Vec<(usize, bool)> {
+    let mut stack: Vec<usize> = Vec::new();
+    heights
+        .iter()
+        .enumerate()
+        .map(|(index, &height)| {
+            while stack.last().is_some_and(|&top| heights[top] < height) {
+                stack.pop();
+            }
+            let view = match stack.last() {
+                Some(&blocker) => (index - blocker, false),
+                None => (index, true),
+            };
+            stack.push(index);
+            view
+        })
+        .collect()
+}
+
+// The scenic score of every tree and whether it's visible from outside,
+// from looking back along every row and column in both directions
+fn views(input: &str) -> Vec<(usize, bool)> {
+    let grid = input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.bytes().map(|byte| byte - b'0').collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let height = grid.len();
+    let width = grid.first().map_or(0, Vec::len);
+
+    let mut lines = Vec::new();
+    for y in 0..height {
+        lines.push((0..width).map(|x| (y, x)).collect::<Vec<_>>());
+    }
+    for x in 0..width {
+        lines.push((0..height).map(|y| (y, x)).collect::<Vec<_>>());
+    }
+
+    let mut views = vec![(1, false); width * height];
+    for mut line in lines {
+        for _ in 0..2 {
+            let heights = line.iter().map(|&(y, x)| grid[y][x]).collect::<Vec<_>>();
+            for (&(y, x), (distance, sees_edge)) in line.iter().zip(look_back(&heights)) {
+                let view = &mut views[y * width + x];
+                view.0 *= distance;
+                view.1 |= sees_edge;
+            }
+            line.reverse();
+        }
+    }
+    views
+}
 
-    let part_1_result = part_1(&input);
-    println!("Day 8 Part 1: {}", part_1_result);
+pub fn part_1_monotonic_stack(input: &str) -> usize {
+    views(input).iter().filter(|(_, visible)| *visible).count()
+}
 
-    let part_2_result = part_2(&input);
-    println!("Day 8 Part 2: {}", part_2_result);
+pub fn part_2_monotonic_stack(input: &str) -> usize {
+    views(input)
+        .iter()
+        .map(|(scenic_score, _)| *scenic_score)
+        .max()
+        .unwrap()
+}
+
+// Tiles the forest into a square of about `factor` forests
+pub fn scale_input(input: &str, factor: usize) -> String {
+    let tiles = (factor as f64).sqrt().ceil() as usize;
+    let rows = input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.repeat(tiles))
+        .collect::<Vec<_>>();
+
+    let mut scaled = String::new();
+    for _ in 0..tiles {
+        for row in &rows {
+            scaled += row;
+            scaled += "\n";
+        }
+    }
+    scaled
 }
 
 #[cfg(test)]
@@ -158,4 +238,12 @@ mod tests {
 
         assert_eq!(part_2(input), 8)
     }
+
+    #[test]
+    fn monotonic_stack_should_match_the_search() {
+        let input = "30373\n25512\n65332\n33549\n35390\n\n";
+
+        assert_eq!(part_1_monotonic_stack(input), 21);
+        assert_eq!(part_2_monotonic_stack(input), 8);
+    }
 }
@@ -1,5 +1,14 @@
+use common::{
+    parse::parse,
+    trace::{Trace, Value},
+};
 use std::{collections::HashSet, f64::consts::SQRT_2};
 
+#[cfg(feature = "embed-input")]
+pub const INPUT: Option<&str> = Some(include_str!("../input.txt"));
+#[cfg(not(feature = "embed-input"))]
+pub const INPUT: Option<&str> = None;
+
 #[derive(Debug)]
 enum Move {
     Up(i32),
@@ -30,26 +39,28 @@ fn new_tail_position(head_x: i32, head_y: i32, tail_x: i32, tail_y: i32) -> (i32
     (new_tail_x, new_tail_y)
 }
 
-fn part_1(input: &str) -> usize {
+// Lines like 'R 4'
+fn parse_moves(input: &str) -> Vec<Move> {
+    parse(input, |parser| {
+        parser.lines(|parser| {
+            let movement = parser.one_of(&[
+                ("U", Move::Up as fn(i32) -> Move),
+                ("D", Move::Down),
+                ("L", Move::Left),
+                ("R", Move::Right),
+            ])?;
+            parser.literal(" ")?;
+            Ok(movement(parser.number()?))
+        })
+    })
+    .unwrap_or_else(|error| panic!("{}", error))
+}
+
+pub fn part_1(input: &str) -> usize {
     let mut current_head_position = (0, 0);
     let mut current_tail_position = (0, 0);
-    input
-        .lines()
-        .filter_map(|line| line.split_once(' '))
-        .filter_map(|(direction, distance)| {
-            if let Ok(distance) = distance.parse::<i32>() {
-                Some((direction, distance))
-            } else {
-                None
-            }
-        })
-        .filter_map(|(direction, distance)| match direction {
-            "U" => Some(Move::Up(distance)),
-            "D" => Some(Move::Down(distance)),
-            "L" => Some(Move::Left(distance)),
-            "R" => Some(Move::Right(distance)),
-            _ => None,
-        })
+    parse_moves(input)
+        .into_iter()
         .flat_map(|movement| {
             let mut visited_positions = vec![(0, 0)];
             let (mut head_x, mut head_y) = current_head_position;
@@ -82,27 +93,30 @@ fn part_1(input: &str) -> usize {
         .len()
 }
 
-fn part_2(input: &str) -> usize {
-    let movements = input
-        .lines()
-        .filter_map(|line| line.split_once(' '))
-        .filter_map(|(direction, distance)| {
-            if let Ok(distance) = distance.parse::<i32>() {
-                Some((direction, distance))
-            } else {
-                None
-            }
-        })
-        .filter_map(|(direction, distance)| match direction {
-            "U" => Some(Move::Up(distance)),
-            "D" => Some(Move::Down(distance)),
-            "L" => Some(Move::Left(distance)),
-            "R" => Some(Move::Right(distance)),
-            _ => None,
-        });
-
-    let mut visited_positions: HashSet<(i32, i32)> = HashSet::from([(0, 0)]);
-    let mut knots: [(i32, i32); 10] = [(0, 0); 10];
+pub const KNOTS: usize = 10;
+
+fn knots_state(knots: &[(i32, i32)]) -> Value {
+    Value::Object(vec![(
+        "knots",
+        Value::List(
+            knots
+                .iter()
+                .map(|&(x, y)| Value::List(vec![Value::Number(x.into()), Value::Number(y.into())]))
+                .collect(),
+        ),
+    )])
+}
+
+// Every position the last knot of a rope with `knot_count` knots moves to,
+// in order and starting at the origin. A step is traced for every step of
+// the head.
+pub fn tail_path_traced(input: &str, knot_count: usize, trace: &mut impl Trace) -> Vec<(i32, i32)> {
+    let movements = parse_moves(input).into_iter();
+
+    let mut path = vec![(0, 0)];
+    let mut knots = vec![(0, 0); knot_count.max(1)];
+    let last_knot = knots.len() - 1;
+    trace.step(|| knots_state(&knots));
     for movement in movements {
         let (mut target_head_x, mut target_head_y) = knots[0];
         match movement {
@@ -124,8 +138,11 @@ fn part_2(input: &str) -> usize {
             let (head_x, head_y) = knots[0];
             let (head_x, head_y) = new_tail_position(target_head_x, target_head_y, head_x, head_y);
             knots[0] = (head_x, head_y);
+            if last_knot == 0 {
+                path.push(knots[0]);
+            }
 
-            for knot_index in 1..=9 {
+            for knot_index in 1..=last_knot {
                 let (head_x, head_y) = knots[knot_index - 1];
                 let (mut tail_x, mut tail_y) = knots[knot_index];
                 if touches(head_x, head_y, tail_x, tail_y) {
@@ -134,28 +151,31 @@ fn part_2(input: &str) -> usize {
                 }
 
                 (tail_x, tail_y) = new_tail_position(head_x, head_y, tail_x, tail_y);
-                if knot_index == 9 {
-                    visited_positions.insert((tail_x, tail_y));
+                if knot_index == last_knot {
+                    path.push((tail_x, tail_y));
                 }
                 knots[knot_index] = (tail_x, tail_y);
             }
+            trace.step(|| knots_state(&knots));
         }
     }
 
-    visited_positions.len()
+    path
 }
 
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    let path = &args[1];
-    let input = std::fs::read_to_string(path)
-        .unwrap_or_else(|error| panic!("No file found at: '{}'.\n{}", path, error));
+pub fn tail_path(input: &str, knot_count: usize) -> Vec<(i32, i32)> {
+    tail_path_traced(input, knot_count, &mut ())
+}
 
-    let part_1_result = part_1(&input);
-    println!("Day 9 Part 1: {}", part_1_result);
+pub fn count_tail_positions(input: &str, knot_count: usize) -> usize {
+    tail_path(input, knot_count)
+        .into_iter()
+        .collect::<HashSet<_>>()
+        .len()
+}
 
-    let part_2_result = part_2(&input);
-    println!("Day 9 Part 2: {}", part_2_result);
+pub fn part_2(input: &str) -> usize {
+    count_tail_positions(input, KNOTS)
 }
 
 #[cfg(test)]
@@ -199,4 +219,11 @@ mod tests {
 
         assert_eq!(part_2(input), 36);
     }
+
+    #[test]
+    fn count_tail_positions_should_match_part_1_for_a_rope_of_two_knots() {
+        let input = "R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20\n\n";
+
+        assert_eq!(count_tail_positions(input, 2), part_1(input));
+    }
 }
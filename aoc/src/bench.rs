@@ -0,0 +1,109 @@
+use std::time::Duration;
+
+use crate::{config::Parameters, solve, Day, Solution, Solver};
+
+pub struct Comparison {
+    pub algorithm: &'static str,
+    // The answer and the median time of each part
+    pub parts: [Result<Solution, String>; 2],
+}
+
+// The median rather than the mean, so one slow run doesn't skew it
+pub fn median(mut times: Vec<Duration>) -> Duration {
+    times.sort_unstable();
+    times[times.len() / 2]
+}
+
+fn time(
+    solver: Solver,
+    input: &str,
+    parameters: &Parameters,
+    runs: usize,
+) -> Result<Solution, String> {
+    let solutions = (0..runs.max(1))
+        .map(|_| solve(solver, input, parameters))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Solution {
+        elapsed: median(solutions.iter().map(|solution| solution.elapsed).collect()),
+        answer: solutions.into_iter().next().unwrap().answer,
+    })
+}
+
+// Runs every algorithm of the day `runs` times
+pub fn compare(day: &Day, input: &str, parameters: &Parameters, runs: usize) -> Vec<Comparison> {
+    day.algorithm_names()
+        .into_iter()
+        .map(|algorithm| {
+            let [part_1, part_2] = day.solvers(algorithm).unwrap();
+            Comparison {
+                algorithm,
+                parts: [
+                    time(part_1, input, parameters, runs),
+                    time(part_2, input, parameters, runs),
+                ],
+            }
+        })
+        .collect()
+}
+
+// A row per algorithm, flagging answers that differ from the first one's
+pub fn table(day: u8, comparisons: &[Comparison]) -> String {
+    let mut table = format!(
+        "Day {}\n{:<18}{:>22}{:>22}\n",
+        day, "Algorithm", "Part 1", "Part 2"
+    );
+    for comparison in comparisons {
+        table += &format!("{:<18}", comparison.algorithm);
+        for part in 0..2 {
+            let expected = comparisons[0].parts[part].as_ref().ok();
+            let cell = match &comparison.parts[part] {
+                Err(_) => "failed".to_string(),
+                Ok(solution)
+                    if expected.is_some_and(|expected| expected.answer != solution.answer) =>
+                {
+                    format!("{:?} (differs)", solution.elapsed)
+                }
+                Ok(solution) => format!("{:?}", solution.elapsed),
+            };
+            table += &format!("{:>22}", cell);
+        }
+        table += "\n";
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn median_should_ignore_a_slow_run() {
+        let times = [3, 1, 250, 2, 4].map(Duration::from_millis).to_vec();
+
+        assert_eq!(median(times), Duration::from_millis(3));
+    }
+
+    #[test]
+    fn compare_should_run_every_algorithm_of_the_day() {
+        let day = crate::find_day(2022, 6).unwrap();
+        let comparisons = compare(
+            day,
+            "mjqjpqmgbljsphdztnvjfqwrcgsmlb",
+            &Parameters::default(),
+            3,
+        );
+
+        assert_eq!(
+            comparisons
+                .iter()
+                .map(|comparison| comparison.algorithm)
+                .collect::<Vec<_>>(),
+            ["default", "bitmask"]
+        );
+        for comparison in &comparisons {
+            assert_eq!(comparison.parts[0].as_ref().unwrap().answer, "7");
+            assert_eq!(comparison.parts[1].as_ref().unwrap().answer, "19");
+        }
+        assert!(!table(6, &comparisons).contains("differs"));
+    }
+}
@@ -0,0 +1,11 @@
+use std::net::TcpListener;
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    let address = args.get(1).map_or("127.0.0.1:8080", |address| address);
+    let listener = TcpListener::bind(address)
+        .unwrap_or_else(|error| panic!("Could not listen on: '{}'.\n{}", address, error));
+
+    println!("Listening on http://{}", address);
+    aoc::server::serve(listener);
+}
@@ -0,0 +1,214 @@
+use std::{collections::BTreeMap, fs, io, path::Path, str::FromStr};
+
+use crate::find_day;
+
+// The values set for a day's parameters, as written in the config or on the
+// command line. Parameters that aren't set use the day's defaults.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Parameters(BTreeMap<String, String>);
+
+impl Parameters {
+    // Panics on a value that doesn't parse, so `solve` reports it the same
+    // way as input it can't parse.
+    pub fn get<T: FromStr>(&self, name: &str, default: T) -> T {
+        match self.0.get(name) {
+            Some(value) => value
+                .parse()
+                .unwrap_or_else(|_| panic!("Invalid value '{}' for parameter '{}'", value, name)),
+            None => default,
+        }
+    }
+
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.0.insert(name.to_string(), value.to_string());
+    }
+}
+
+// Read from `aoc.toml`:
+//
+//   year = 2022
+//
+//   [2022.day_9]
+//   knots = 2
+//
+// where `year` is the year to run when none is given.
+#[derive(Debug, Default)]
+pub struct Config {
+    pub year: Option<u16>,
+    days: BTreeMap<(u16, u8), Parameters>,
+}
+
+impl Config {
+    pub fn parse(text: &str) -> Result<Config, String> {
+        let table = text
+            .parse::<toml::Table>()
+            .map_err(|error| error.to_string())?;
+        let mut config = Config::default();
+
+        for (key, value) in table {
+            if key == "year" {
+                let year = value
+                    .as_integer()
+                    .and_then(|year| u16::try_from(year).ok())
+                    .ok_or_else(|| format!("Expected a year for 'year', found '{}'", value))?;
+                config.year = Some(year);
+                continue;
+            }
+
+            let year = key
+                .parse::<u16>()
+                .map_err(|_| format!("Unknown key '{}'", key))?;
+            let days = value
+                .as_table()
+                .ok_or_else(|| format!("Expected a table of days for '{}'", key))?;
+            for (day, parameters) in days {
+                let parameters = parameters.as_table().ok_or_else(|| {
+                    format!("Expected a table of parameters for '{}.{}'", key, day)
+                })?;
+                for (name, value) in parameters {
+                    let value = match value {
+                        toml::Value::String(value) => value.clone(),
+                        value => value.to_string(),
+                    };
+                    config.set_parameter(year, day, name, &value)?;
+                }
+            }
+        }
+        Ok(config)
+    }
+
+    // A missing file is the same as an empty one
+    pub fn load(path: &Path) -> Result<Config, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => {
+                Config::parse(&text).map_err(|error| format!("{}: {}", path.display(), error))
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(error) => Err(format!("{}: {}", path.display(), error)),
+        }
+    }
+
+    // Overrides a parameter with an assignment such as `day_9.knots=2`
+    pub fn set(&mut self, year: u16, assignment: &str) -> Result<(), String> {
+        let (day, name, value) = assignment
+            .split_once('.')
+            .and_then(|(day, rest)| {
+                let (name, value) = rest.split_once('=')?;
+                Some((day, name, value))
+            })
+            .ok_or_else(|| format!("Expected day_N.name=value, found '{}'", assignment))?;
+        self.set_parameter(year, day, name, value)
+    }
+
+    fn set_parameter(
+        &mut self,
+        year: u16,
+        day: &str,
+        name: &str,
+        value: &str,
+    ) -> Result<(), String> {
+        let number = day
+            .strip_prefix("day_")
+            .and_then(|number| number.parse::<u8>().ok())
+            .ok_or_else(|| format!("Expected day_N, found '{}'", day))?;
+        let day = find_day(year, number)
+            .ok_or_else(|| format!("No solution for {} day {}", year, number))?;
+        let Some(parameter) = day
+            .parameters
+            .iter()
+            .find(|parameter| parameter.name == name)
+        else {
+            return Err(if day.parameters.is_empty() {
+                format!("Day {} has no parameters", number)
+            } else {
+                format!(
+                    "Day {} has no parameter '{}', expected one of: {}",
+                    number,
+                    name,
+                    day.parameters
+                        .iter()
+                        .map(|parameter| parameter.name)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            });
+        };
+        (parameter.check)(value).map_err(|error| {
+            format!(
+                "Invalid value '{}' for day {} parameter '{}': {}",
+                value, number, name, error
+            )
+        })?;
+
+        self.days
+            .entry((year, number))
+            .or_default()
+            .set(name, value);
+        Ok(())
+    }
+
+    pub fn parameters(&self, year: u16, day: u8) -> Parameters {
+        self.days.get(&(year, day)).cloned().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_should_read_the_year_and_the_parameters_of_each_day() {
+        let config = Config::parse(
+            "year = 2022\n\n[2022.day_9]\nknots = 2\n\n[2022.day_7]\ntotal_space = \"100\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(config.year, Some(2022));
+        assert_eq!(config.parameters(2022, 9).get("knots", 10), 2);
+        assert_eq!(config.parameters(2022, 7).get("total_space", 0), 100);
+        assert_eq!(config.parameters(2022, 7).get("required_space", 5), 5);
+    }
+
+    #[test]
+    fn parse_should_reject_parameters_the_day_does_not_have() {
+        assert_eq!(
+            Config::parse("[2022.day_9]\nnots = 2\n").unwrap_err(),
+            "Day 9 has no parameter 'nots', expected one of: knots"
+        );
+        assert_eq!(
+            Config::parse("[2022.day_3]\nknots = 2\n").unwrap_err(),
+            "Day 3 has no parameters"
+        );
+    }
+
+    #[test]
+    fn parse_should_reject_invalid_values() {
+        assert_eq!(
+            Config::parse("[2022.day_9]\nknots = 0\n").unwrap_err(),
+            "Invalid value '0' for day 9 parameter 'knots': expected a number above 0"
+        );
+        assert!(Config::parse("[2022.day_7]\ntotal_space = \"lots\"\n").is_err());
+        for parameter in [
+            "day_6.packet_window=0",
+            "day_6.message_window=0",
+            "day_10.screen_width=0",
+            "day_11.relief=0",
+            "day_2.opponent=nobody",
+        ] {
+            assert!(
+                Config::default().set(2022, parameter).is_err(),
+                "{}",
+                parameter
+            );
+        }
+    }
+
+    #[test]
+    fn set_should_override_the_configured_value() {
+        let mut config = Config::parse("[2022.day_9]\nknots = 2\n").unwrap();
+        config.set(2022, "day_9.knots=5").unwrap();
+
+        assert_eq!(config.parameters(2022, 9).get("knots", 10), 5);
+        assert!(config.set(2022, "knots=5").is_err());
+    }
+}
@@ -0,0 +1,190 @@
+use common::examples::Example;
+
+use crate::{catch_panic, config::Parameters, Day};
+
+fn decode_entities(string: &str) -> String {
+    string
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+fn strip_tags(string: &str) -> String {
+    let mut result = String::with_capacity(string.len());
+    let mut in_tag = false;
+    for character in string.chars() {
+        match character {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            character if !in_tag => result.push(character),
+            _ => {}
+        }
+    }
+    decode_entities(&result)
+}
+
+// Everything between each `open` and the next `close`, with the position of
+// the `open`.
+fn find_all<'a>(html: &'a str, open: &str, close: &str) -> Vec<(usize, &'a str)> {
+    let mut found = Vec::new();
+    let mut offset = 0;
+    while let Some(start) = html[offset..].find(open) {
+        let content_start = offset + start + open.len();
+        let Some(length) = html[content_start..].find(close) else {
+            break;
+        };
+        found.push((offset + start, &html[content_start..content_start + length]));
+        offset = content_start + length + close.len();
+    }
+    found
+}
+
+// Answers are highlighted as `<code><em>24000</em></code>`, or the other way
+// around.
+fn highlighted_values(article: &str) -> Vec<String> {
+    let mut values = find_all(article, "<code><em>", "</em></code>");
+    values.extend(find_all(article, "<em><code>", "</code></em>"));
+    values.sort_unstable();
+    values
+        .into_iter()
+        .map(|(_, value)| strip_tags(value).trim().to_string())
+        .collect()
+}
+
+// Pulls the example inputs out of a saved puzzle page along with the example
+// answer of each part. An answer is attached to the first example block that
+// the day's solver turns into one of the part's highlighted values. When none
+// does, the last highlighted value is attached to the part's first block and
+// a warning is returned so it can be checked by hand.
+pub fn extract(html: &str, day: &Day) -> (Vec<Example>, Vec<String>) {
+    let mut examples: Vec<Example> = Vec::new();
+    let mut warnings = Vec::new();
+
+    for (part, (_, article)) in find_all(html, "<article", "</article>")
+        .into_iter()
+        .take(2)
+        .enumerate()
+    {
+        let first_block_of_article = examples.len();
+        for (_, block) in find_all(article, "<pre><code>", "</code></pre>") {
+            examples.push(Example {
+                input: strip_tags(block),
+                ..Example::default()
+            });
+        }
+
+        let values = highlighted_values(article);
+        let Some(last_value) = values.last() else {
+            continue;
+        };
+        let solver = if part == 0 { day.part_1 } else { day.part_2 };
+        let matching = examples.iter().enumerate().find_map(|(index, example)| {
+            catch_panic(|| solver(&example.input, &Parameters::default()))
+                .ok()
+                .filter(|answer| values.contains(answer))
+                .map(|answer| (index, answer))
+        });
+        let (index, answer) = match matching {
+            Some(matching) => matching,
+            None if examples.is_empty() => continue,
+            None => {
+                let index = first_block_of_article.min(examples.len() - 1);
+                warnings.push(format!(
+                    "Part {} answer '{}' doesn't match the solver, attached it to the part's first example",
+                    part + 1,
+                    last_value
+                ));
+                (index, last_value.clone())
+            }
+        };
+        if part == 0 {
+            examples[index].part_1 = Some(answer);
+        } else {
+            examples[index].part_2 = Some(answer);
+        }
+    }
+
+    // Blocks that aren't an example for either part are usually diagrams
+    examples.retain(|example| example.part_1.is_some() || example.part_2.is_some());
+    for (index, example) in examples.iter_mut().enumerate() {
+        example.name = (index + 1).to_string();
+    }
+    (examples, warnings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{find_day, solve, YEARS};
+    use std::path::Path;
+
+    const PAGE: &str = "<main>\
+        <article class=\"day-desc\"><h2>--- Day 9: Rope Bridge ---</h2>\
+        <p>For example:</p><pre><code>R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2\n</code></pre>\
+        <p>Drawing:</p><pre><code>..##..\n<em>#</em>...\n</code></pre>\
+        <p>So, there are <code><em>13</em></code> positions.</p></article>\
+        <p>Your puzzle answer was <code>6522</code>.</p>\
+        <article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2>\
+        <p>Now, <em>ten</em> knots. A larger example:</p>\
+        <pre><code>R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20\n</code></pre>\
+        <p>Now, the tail visits <code><em>36</em></code> positions.</p></article></main>";
+
+    #[test]
+    fn extract_should_attach_each_answer_to_the_example_it_belongs_to() {
+        let (examples, warnings) = extract(PAGE, find_day(2022, 9).unwrap());
+
+        assert!(warnings.is_empty());
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].name, "1");
+        assert_eq!(
+            examples[0].input,
+            "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2\n"
+        );
+        assert_eq!(examples[0].part_1, Some("13".to_string()));
+        assert_eq!(examples[0].part_2, None);
+        assert_eq!(examples[1].name, "2");
+        assert_eq!(examples[1].part_2, Some("36".to_string()));
+    }
+
+    #[test]
+    fn extract_should_warn_when_no_example_matches_an_answer() {
+        let page = "<article><pre><code>1 &lt; 2\n</code></pre><code><em>99</em></code></article>";
+        let (examples, warnings) = extract(page, find_day(2022, 1).unwrap());
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(examples[0].input, "1 < 2\n");
+        assert_eq!(examples[0].part_1, Some("99".to_string()));
+    }
+
+    #[test]
+    fn every_day_should_match_its_recorded_examples() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        for year in &YEARS {
+            for day in year.days {
+                let name = format!("{} day {}", year.year, day.number);
+                let examples = common::examples::load(root.join(year.day_directory(day.number)));
+                assert!(!examples.is_empty(), "{} has no examples", name);
+                for example in examples {
+                    for (part, solver, answer) in [
+                        (1, day.part_1, &example.part_1),
+                        (2, day.part_2, &example.part_2),
+                    ] {
+                        let Some(answer) = answer else {
+                            continue;
+                        };
+                        let solution = solve(solver, &example.input, &Parameters::default())
+                            .unwrap_or_else(|error| panic!("{}: {}", name, error));
+                        assert_eq!(
+                            &solution.answer, answer,
+                            "{} example '{}' part {}",
+                            name, example.name, part
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,39 @@
+// Helpers for the sentences `--explain` prints
+
+// "a", "a and b", "a, b and c"
+pub fn list(items: &[String]) -> String {
+    match items {
+        [] => String::new(),
+        [item] => item.clone(),
+        [rest @ .., last] => format!("{} and {}", rest.join(", "), last),
+    }
+}
+
+// "1 item", "2 items"
+pub fn count(count: usize, noun: &str) -> String {
+    match count {
+        1 => format!("1 {}", noun),
+        count => format!("{} {}s", count, noun),
+    }
+}
+
+// Counted from 1, like an editor would
+pub fn square((row, column): (usize, usize)) -> String {
+    format!("row {}, column {}", row + 1, column + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn list_should_join_the_last_item_with_and() {
+        let items = ["a", "b", "c"].map(String::from);
+
+        assert_eq!(list(&items[..1]), "a");
+        assert_eq!(list(&items[..2]), "a and b");
+        assert_eq!(list(&items), "a, b and c");
+        assert_eq!(square((0, 4)), "row 1, column 5");
+        assert_eq!(count(1, "item"), "1 item");
+    }
+}
@@ -0,0 +1,385 @@
+use std::collections::{BTreeMap, HashMap};
+
+use common::csv;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Export {
+    event: String,
+    members: HashMap<String, ExportMember>,
+}
+
+#[derive(Deserialize)]
+struct ExportMember {
+    id: u64,
+    name: Option<String>,
+    stars: u32,
+    local_score: u64,
+    completion_day_level: HashMap<String, HashMap<String, ExportStar>>,
+}
+
+#[derive(Deserialize)]
+struct ExportStar {
+    get_star_ts: i64,
+}
+
+pub struct Member {
+    pub id: u64,
+    pub name: String,
+    pub stars: u32,
+    pub local_score: u64,
+    // Unix timestamp of each star, keyed by day and part
+    pub completions: BTreeMap<(u8, u8), i64>,
+}
+
+pub struct Leaderboard {
+    pub year: i32,
+    pub members: Vec<Member>,
+}
+
+pub struct Table {
+    pub header: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    fn new(header: &[&str]) -> Table {
+        Table {
+            header: header.iter().map(|column| column.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn to_text(&self) -> String {
+        let widths = (0..self.header.len())
+            .map(|column| {
+                self.rows
+                    .iter()
+                    .map(|row| row[column].chars().count())
+                    .chain([self.header[column].chars().count()])
+                    .max()
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let format_row = |row: &[String]| {
+            row.iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        };
+
+        let mut lines = vec![format_row(&self.header)];
+        lines.push(
+            widths
+                .iter()
+                .map(|width| "-".repeat(*width))
+                .collect::<Vec<_>>()
+                .join("  "),
+        );
+        lines.extend(self.rows.iter().map(|row| format_row(row)));
+        lines.join("\n") + "\n"
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut lines = vec![csv::row(&self.header)];
+        lines.extend(self.rows.iter().map(|row| csv::row(row)));
+        lines.join("\n") + "\n"
+    }
+}
+
+// Days since 1970-01-01 of a date in the proleptic Gregorian calendar
+pub fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+// Puzzles unlock at midnight EST, which is 05:00 UTC
+pub fn unlock_timestamp(year: i32, day: u8) -> i64 {
+    days_from_civil(year as i64, 12, day as i64) * 86400 + 5 * 3600
+}
+
+pub fn format_timestamp(timestamp: i64) -> String {
+    let (year, month, day) = civil_from_days(timestamp.div_euclid(86400));
+    let seconds = timestamp.rem_euclid(86400);
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+pub fn format_duration(seconds: i64) -> String {
+    let sign = if seconds < 0 { "-" } else { "" };
+    let seconds = seconds.abs();
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600 % 24,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+    if seconds >= 86400 {
+        format!("{}{}d {}", sign, seconds / 86400, time)
+    } else {
+        format!("{}{}", sign, time)
+    }
+}
+
+impl Leaderboard {
+    pub fn from_json(json: &str) -> Result<Leaderboard, String> {
+        let export: Export = serde_json::from_str(json).map_err(|error| error.to_string())?;
+        let year = export
+            .event
+            .parse()
+            .map_err(|_| format!("Unexpected event '{}'", export.event))?;
+
+        let mut members = export
+            .members
+            .into_values()
+            .map(|member| {
+                let mut completions = BTreeMap::new();
+                for (day, parts) in member.completion_day_level {
+                    for (part, star) in parts {
+                        let key = (day.parse().unwrap_or(0), part.parse().unwrap_or(0));
+                        completions.insert(key, star.get_star_ts);
+                    }
+                }
+                Member {
+                    id: member.id,
+                    name: member
+                        .name
+                        .unwrap_or_else(|| format!("(anonymous user #{})", member.id)),
+                    stars: member.stars,
+                    local_score: member.local_score,
+                    completions,
+                }
+            })
+            .collect::<Vec<_>>();
+        members.sort_by_key(|member| member.id);
+
+        Ok(Leaderboard { year, members })
+    }
+
+    // Everyone who got a star scores one point less than the member before
+    // them, starting from the number of members.
+    pub fn local_scores(&self) -> HashMap<u64, u64> {
+        let mut scores = self
+            .members
+            .iter()
+            .map(|member| (member.id, 0))
+            .collect::<HashMap<_, _>>();
+        for day in 1..=25 {
+            for part in 1..=2 {
+                let mut finishers = self
+                    .members
+                    .iter()
+                    .filter_map(|member| Some((*member.completions.get(&(day, part))?, member.id)))
+                    .collect::<Vec<_>>();
+                finishers.sort_unstable();
+                for (rank, (_, id)) in finishers.into_iter().enumerate() {
+                    *scores.get_mut(&id).unwrap() += (self.members.len() - rank) as u64;
+                }
+            }
+        }
+        scores
+    }
+
+    pub fn scores_table(&self) -> Table {
+        let local_scores = self.local_scores();
+        let mut members = self.members.iter().collect::<Vec<_>>();
+        members.sort_by_key(|member| (std::cmp::Reverse(local_scores[&member.id]), member.id));
+
+        let mut table = Table::new(&["Rank", "Member", "Stars", "Exported score", "Local score"]);
+        for (rank, member) in members.into_iter().enumerate() {
+            table.rows.push(vec![
+                (rank + 1).to_string(),
+                member.name.clone(),
+                member.stars.to_string(),
+                member.local_score.to_string(),
+                local_scores[&member.id].to_string(),
+            ]);
+        }
+        table
+    }
+
+    pub fn stars_table(&self) -> Table {
+        let mut table = Table::new(&["Member", "Day", "Part", "Completed (UTC)", "Since unlock"]);
+        for member in &self.members {
+            for (&(day, part), &timestamp) in &member.completions {
+                table.rows.push(vec![
+                    member.name.clone(),
+                    day.to_string(),
+                    part.to_string(),
+                    format_timestamp(timestamp),
+                    format_duration(timestamp - unlock_timestamp(self.year, day)),
+                ]);
+            }
+        }
+        table
+    }
+
+    pub fn deltas_table(&self) -> Table {
+        let mut table = Table::new(&["Member", "Day", "Part 1", "Part 2", "Delta"]);
+        for member in &self.members {
+            for day in 1..=25 {
+                let Some(&part_1) = member.completions.get(&(day, 1)) else {
+                    continue;
+                };
+                let unlock = unlock_timestamp(self.year, day);
+                let part_2 = member.completions.get(&(day, 2));
+                table.rows.push(vec![
+                    member.name.clone(),
+                    day.to_string(),
+                    format_duration(part_1 - unlock),
+                    part_2.map_or("-".to_string(), |part_2| format_duration(part_2 - unlock)),
+                    part_2.map_or("-".to_string(), |part_2| format_duration(part_2 - part_1)),
+                ]);
+            }
+        }
+        table
+    }
+
+    // Members who finished both parts of a day rank by when they got the
+    // second star, ahead of those who only have the first.
+    pub fn days_table(&self) -> Table {
+        let mut table = Table::new(&["Day", "Rank", "Member", "Part 1", "Part 2"]);
+        for day in 1..=25 {
+            let unlock = unlock_timestamp(self.year, day);
+            let mut finishers = self
+                .members
+                .iter()
+                .filter_map(|member| {
+                    let part_1 = *member.completions.get(&(day, 1))?;
+                    let part_2 = member.completions.get(&(day, 2)).copied();
+                    Some((
+                        part_2.is_none(),
+                        part_2.unwrap_or(part_1),
+                        member.id,
+                        member,
+                    ))
+                })
+                .collect::<Vec<_>>();
+            finishers.sort_unstable_by_key(|&(only_part_1, timestamp, id, _)| {
+                (only_part_1, timestamp, id)
+            });
+            for (rank, (_, _, _, member)) in finishers.into_iter().enumerate() {
+                table.rows.push(vec![
+                    day.to_string(),
+                    (rank + 1).to_string(),
+                    member.name.clone(),
+                    format_duration(member.completions[&(day, 1)] - unlock),
+                    member
+                        .completions
+                        .get(&(day, 2))
+                        .map_or("-".to_string(), |part_2| format_duration(part_2 - unlock)),
+                ]);
+            }
+        }
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Day 1 of 2022 unlocked at 1669870800
+    const EXPORT: &str = r#"{
+        "event": "2022",
+        "owner_id": 1,
+        "members": {
+            "1": {"id": 1, "name": "Alice", "stars": 3, "local_score": 8, "global_score": 0, "last_star_ts": 1669957800,
+                  "completion_day_level": {
+                      "1": {"1": {"get_star_ts": 1669871100, "star_index": 1}, "2": {"get_star_ts": 1669871400, "star_index": 2}},
+                      "2": {"1": {"get_star_ts": 1669957800, "star_index": 3}}}},
+            "2": {"id": 2, "name": null, "stars": 2, "local_score": 5, "global_score": 0, "last_star_ts": 1669871300,
+                  "completion_day_level": {
+                      "1": {"1": {"get_star_ts": 1669870900, "star_index": 1}, "2": {"get_star_ts": 1669871300, "star_index": 2}}}}
+        }
+    }"#;
+
+    #[test]
+    fn unlock_timestamp_should_be_midnight_est() {
+        assert_eq!(unlock_timestamp(2022, 1), 1669870800);
+        assert_eq!(
+            format_timestamp(unlock_timestamp(2022, 25)),
+            "2022-12-25 05:00:00"
+        );
+        assert_eq!(format_duration(90061), "1d 01:01:01");
+    }
+
+    #[test]
+    fn local_scores_should_give_earlier_stars_more_points() {
+        let leaderboard = Leaderboard::from_json(EXPORT).unwrap();
+        let scores = leaderboard.local_scores();
+
+        assert_eq!(scores[&1], 1 + 1 + 2);
+        assert_eq!(scores[&2], 2 + 2);
+    }
+
+    #[test]
+    fn deltas_table_should_show_the_time_between_parts() {
+        let leaderboard = Leaderboard::from_json(EXPORT).unwrap();
+        let table = leaderboard.deltas_table();
+
+        assert_eq!(
+            table.rows[0],
+            ["Alice", "1", "00:05:00", "00:10:00", "00:05:00"]
+        );
+        assert_eq!(table.rows[1], ["Alice", "2", "00:10:00", "-", "-"]);
+        assert_eq!(table.rows[2][0], "(anonymous user #2)");
+        assert_eq!(table.rows[2][4], "00:06:40");
+    }
+
+    #[test]
+    fn days_table_should_rank_by_the_second_star() {
+        let leaderboard = Leaderboard::from_json(EXPORT).unwrap();
+        let table = leaderboard.days_table();
+
+        assert_eq!(table.rows[0][..3], ["1", "1", "(anonymous user #2)"]);
+        assert_eq!(table.rows[1][..3], ["1", "2", "Alice"]);
+        assert_eq!(table.rows[2][..3], ["2", "1", "Alice"]);
+    }
+
+    #[test]
+    fn to_csv_should_quote_cells_with_commas() {
+        let mut table = Table::new(&["Member", "Stars"]);
+        table
+            .rows
+            .push(vec!["Doe, Jane".to_string(), "2".to_string()]);
+
+        assert_eq!(table.to_csv(), "Member,Stars\n\"Doe, Jane\",2\n");
+        assert_eq!(
+            table.to_text(),
+            "Member     Stars\n---------  -----\nDoe, Jane  2\n"
+        );
+    }
+}
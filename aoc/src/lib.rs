@@ -0,0 +1,278 @@
+use common::{explore::Explore, trace::JsonLines};
+use config::Parameters;
+use report::Visualization;
+use std::{
+    fmt::Display,
+    fs, io,
+    panic::{self, UnwindSafe},
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+pub mod bench;
+pub mod config;
+pub mod examples;
+pub mod explain;
+pub mod leaderboard;
+pub mod profile;
+pub mod repl;
+pub mod report;
+pub mod scaffold;
+pub mod scale;
+pub mod server;
+pub mod status;
+pub mod year_2022;
+
+pub type Explorer = fn(&str) -> Box<dyn Explore>;
+pub type Solver = fn(&str, &Parameters) -> String;
+pub type Visualizer = fn(&str, &Parameters) -> Visualization;
+pub type Tracer = fn(&str, &Parameters, &mut JsonLines) -> String;
+pub type Scaler = fn(&str, usize) -> String;
+pub type Explainer = fn(&str, &Parameters) -> String;
+
+// Another way of solving both parts of a day, picked with `--algo`
+pub struct Algorithm {
+    pub name: &'static str,
+    pub part_1: Solver,
+    pub part_2: Solver,
+}
+
+// Something a day does with its input other than solving it, picked with
+// `--mode`. It prints instead of the answers.
+pub struct Mode {
+    pub name: &'static str,
+    pub run: fn(&str, &Parameters) -> String,
+}
+
+// A value a day reads from `config`. `check` runs when the config is read,
+// so a bad value is reported before anything is solved.
+pub struct Parameter {
+    pub name: &'static str,
+    pub check: fn(&str) -> Result<(), String>,
+}
+
+impl Parameter {
+    // Any text, such as a path
+    pub const fn text(name: &'static str) -> Parameter {
+        Parameter {
+            name,
+            check: check_text,
+        }
+    }
+
+    pub const fn parsed<T: FromStr>(name: &'static str) -> Parameter
+    where
+        T::Err: Display,
+    {
+        Parameter {
+            name,
+            check: check_parsed::<T>,
+        }
+    }
+
+    // A number above zero, for sizes and counts that can't be empty
+    pub const fn positive<T: FromStr + Default + PartialOrd>(name: &'static str) -> Parameter
+    where
+        T::Err: Display,
+    {
+        Parameter {
+            name,
+            check: check_positive::<T>,
+        }
+    }
+}
+
+fn check_text(_: &str) -> Result<(), String> {
+    Ok(())
+}
+
+fn check_parsed<T: FromStr>(value: &str) -> Result<(), String>
+where
+    T::Err: Display,
+{
+    value
+        .parse::<T>()
+        .map(drop)
+        .map_err(|error| error.to_string())
+}
+
+fn check_positive<T: FromStr + Default + PartialOrd>(value: &str) -> Result<(), String>
+where
+    T::Err: Display,
+{
+    match value.parse::<T>() {
+        Ok(number) if number > T::default() => Ok(()),
+        Ok(_) => Err("expected a number above 0".to_string()),
+        Err(error) => Err(error.to_string()),
+    }
+}
+
+// What `part_1` and `part_2` are called next to a day's other algorithms
+pub const DEFAULT_ALGORITHM: &str = "default";
+
+pub struct Day {
+    pub number: u8,
+    // Only set for days whose parsing is separate from solving
+    pub parse: Option<fn(&str, &Parameters)>,
+    pub part_1: Solver,
+    pub part_2: Solver,
+    // The parameters the parts read, see `config`
+    pub parameters: &'static [Parameter],
+    pub algorithms: &'static [Algorithm],
+    pub modes: &'static [Mode],
+    pub explore: Option<Explorer>,
+    // Says how each part's answer was found, for `--explain`
+    pub explain: Option<[Explainer; 2]>,
+    // Solve the parts while writing every step of the simulation to a trace
+    pub trace: Option<[Tracer; 2]>,
+    // Makes an input about `factor` times the work of the given one
+    pub scale: Option<Scaler>,
+    // Set when built with the `embed-inputs` feature
+    pub input: Option<&'static str>,
+    // Drawn into the report, see `report`
+    pub visualize: Option<Visualizer>,
+}
+
+impl Day {
+    pub fn part(&self, part: u8) -> Option<Solver> {
+        match part {
+            1 => Some(self.part_1),
+            2 => Some(self.part_2),
+            _ => None,
+        }
+    }
+
+    pub fn algorithm_names(&self) -> Vec<&'static str> {
+        let mut names = vec![DEFAULT_ALGORITHM];
+        names.extend(self.algorithms.iter().map(|algorithm| algorithm.name));
+        names
+    }
+
+    // Both parts solved with the algorithm called `name`
+    pub fn solvers(&self, name: &str) -> Option<[Solver; 2]> {
+        if name == DEFAULT_ALGORITHM {
+            return Some([self.part_1, self.part_2]);
+        }
+        self.algorithms
+            .iter()
+            .find(|algorithm| algorithm.name == name)
+            .map(|algorithm| [algorithm.part_1, algorithm.part_2])
+    }
+}
+
+pub struct Year {
+    pub year: u16,
+    // Where the year's day crates live, relative to the repository root
+    pub directory: &'static str,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    pub fn find_day(&self, number: u8) -> Option<&'static Day> {
+        self.days.iter().find(|day| day.number == number)
+    }
+
+    pub fn day_directory(&self, day: u8) -> PathBuf {
+        Path::new(self.directory).join(format!("day_{}", day))
+    }
+
+    pub fn input_path(&self, day: u8) -> PathBuf {
+        self.day_directory(day).join("input.txt")
+    }
+
+    // The embedded input when there is one, otherwise the one on disk
+    pub fn read_input(&self, day: &Day) -> io::Result<String> {
+        match day.input {
+            Some(input) => Ok(input.to_string()),
+            None => fs::read_to_string(self.input_path(day.number)),
+        }
+    }
+}
+
+pub static YEARS: [Year; 1] = [Year {
+    year: 2022,
+    directory: "2022",
+    days: &year_2022::DAYS,
+}];
+
+pub fn find_year(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|candidate| candidate.year == year)
+}
+
+pub fn latest_year() -> &'static Year {
+    YEARS.iter().max_by_key(|year| year.year).unwrap()
+}
+
+pub fn find_day(year: u16, day: u8) -> Option<&'static Day> {
+    find_year(year)?.find_day(day)
+}
+
+#[derive(Debug)]
+pub struct Solution {
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+// The solvers panic on input they can't parse, so the panic is caught and
+// its message returned instead of taking the caller down with it.
+pub fn catch_panic<T>(function: impl FnOnce() -> T + UnwindSafe) -> Result<T, String> {
+    panic::catch_unwind(function).map_err(|payload| {
+        if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "Solver panicked".to_string()
+        }
+    })
+}
+
+pub fn solve(solver: Solver, input: &str, parameters: &Parameters) -> Result<Solution, String> {
+    let start = Instant::now();
+    let answer = catch_panic(|| solver(input, parameters))?;
+
+    Ok(Solution {
+        answer,
+        elapsed: start.elapsed(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_day_should_return_the_day_with_the_given_number() {
+        assert_eq!(find_day(2022, 7).unwrap().number, 7);
+        assert!(find_day(2022, 13).is_none());
+        assert!(find_day(2015, 7).is_none());
+    }
+
+    #[test]
+    fn input_path_should_be_inside_the_day_directory() {
+        let year = find_year(2022).unwrap();
+
+        assert_eq!(year.input_path(7), Path::new("2022/day_7/input.txt"));
+    }
+
+    #[test]
+    fn solve_should_return_the_answer_of_the_solver() {
+        let day = find_day(2022, 1).unwrap();
+        let solution = solve(day.part_1, "1000\n2000\n\n4000\n", &Parameters::default()).unwrap();
+
+        assert_eq!(solution.answer, "4000");
+    }
+
+    #[test]
+    fn solve_should_return_the_panic_message_when_the_solver_fails() {
+        let day = find_day(2022, 7).unwrap();
+
+        assert!(solve(
+            day.part_1,
+            "$ cd /\n$ ls\nfoo bar\n",
+            &Parameters::default()
+        )
+        .is_err());
+    }
+}
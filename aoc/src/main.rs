@@ -0,0 +1,522 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter},
+    panic::AssertUnwindSafe,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use common::trace::JsonLines;
+
+use aoc::{
+    config::Config,
+    leaderboard::Leaderboard,
+    profile::{measure, Usage},
+    solve,
+    status::{Solve, SolveLog, SOLVE_LOG},
+    Day, Year,
+};
+
+#[cfg(feature = "profile")]
+#[global_allocator]
+static ALLOCATOR: aoc::profile::CountingAllocator = aoc::profile::CountingAllocator;
+
+const USAGE: &str = "Usage:
+  aoc run [year] [day] [input path] [--trace <path> | --scale <n>] [--algo <name>] [--explain] [options]
+  aoc run [year] [day] --list-algos
+  aoc run [year] [day] [input path] --mode <name> [options]
+  aoc run [year] [day] --list-modes
+  aoc bench [year] [day...] [--runs <n>] [options]
+  aoc repl [year] <day> [input path] [options]
+  aoc profile [year] [day...] [options]
+  aoc examples [year] <day> <saved puzzle page> [options]
+  aoc report [year] [output directory] [options]
+  aoc verify [year] <day> <part> <answer> [input path] [options]
+  aoc status [year] [options]
+  aoc leaderboard <exported json> [scores|stars|deltas|days] [--csv]
+  aoc new <year> <day>
+
+Options:
+  --config <path>           Read the config from <path> instead of aoc.toml
+  --set day_N.name=value    Set a parameter of a day, overriding the config";
+
+// Takes `--config` and `--set` out of the arguments and the year from the
+// first remaining argument when it is one. Without a year the config's year
+// is used, or else the latest year.
+fn configure(args: &[String]) -> (&'static Year, Config, Vec<String>) {
+    let mut config_path = "aoc.toml".to_string();
+    let mut assignments = Vec::new();
+    let mut positional = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--config" => config_path = args.next().unwrap_or_else(|| panic!("{}", USAGE)).clone(),
+            "--set" => assignments.push(args.next().unwrap_or_else(|| panic!("{}", USAGE))),
+            _ => positional.push(arg.clone()),
+        }
+    }
+
+    let mut config = Config::load(Path::new(&config_path))
+        .unwrap_or_else(|error| panic!("Could not read the config.\n{}", error));
+    let year = match positional.first().and_then(|year| year.parse::<u16>().ok()) {
+        Some(year) if year > 25 => {
+            positional.remove(0);
+            year
+        }
+        _ => config.year.unwrap_or_else(|| aoc::latest_year().year),
+    };
+    let year = aoc::find_year(year).unwrap_or_else(|| panic!("No solutions for {}", year));
+    for assignment in assignments {
+        config
+            .set(year.year, assignment)
+            .unwrap_or_else(|error| panic!("Could not set '{}'.\n{}", assignment, error));
+    }
+    (year, config, positional)
+}
+
+fn day_argument(year: &Year, argument: Option<&String>) -> &'static Day {
+    argument
+        .and_then(|day| day.parse().ok())
+        .map(|day| {
+            year.find_day(day)
+                .unwrap_or_else(|| panic!("No solution for {} day {}", year.year, day))
+        })
+        .unwrap_or_else(|| panic!("{}", USAGE))
+}
+
+// Removes `name` and the value following it from the arguments
+fn take_option(args: &mut Vec<String>, name: &str) -> Option<String> {
+    let index = args.iter().position(|arg| arg == name)?;
+    if index + 1 >= args.len() {
+        panic!("{}", USAGE);
+    }
+    args.remove(index);
+    Some(args.remove(index))
+}
+
+// Removes `name` from the arguments, returning whether it was there
+fn take_flag(args: &mut Vec<String>, name: &str) -> bool {
+    let index = args.iter().position(|arg| arg == name);
+    if let Some(index) = index {
+        args.remove(index);
+    }
+    index.is_some()
+}
+
+fn read_file(path: &Path) -> String {
+    std::fs::read_to_string(path)
+        .unwrap_or_else(|error| panic!("No file found at: '{}'.\n{}", path.display(), error))
+}
+
+fn read_input(year: &Year, day: &Day, path: Option<&String>) -> String {
+    match path {
+        Some(path) => read_file(Path::new(path)),
+        None => year.read_input(day).unwrap_or_else(|error| {
+            panic!(
+                "No file found at: '{}'.\n{}",
+                year.input_path(day.number).display(),
+                error
+            )
+        }),
+    }
+}
+
+fn run(args: &[String]) {
+    let mut args = args.to_vec();
+    let mut trace = take_option(&mut args, "--trace").map(|path| {
+        let file = File::create(&path)
+            .unwrap_or_else(|error| panic!("Could not create '{}'.\n{}", path, error));
+        (path, BufWriter::new(file))
+    });
+    let scale = take_option(&mut args, "--scale").map(|scale| {
+        scale
+            .parse::<usize>()
+            .unwrap_or_else(|_| panic!("{}", USAGE))
+    });
+    let algorithm = take_option(&mut args, "--algo");
+    let list_algorithms = take_flag(&mut args, "--list-algos");
+    let mode = take_option(&mut args, "--mode");
+    let list_modes = take_flag(&mut args, "--list-modes");
+    let explain = take_flag(&mut args, "--explain");
+    let (year, config, args) = configure(&args);
+    let days = match args.first() {
+        Some(_) => vec![day_argument(year, args.first())],
+        None => year.days.iter().collect(),
+    };
+
+    for day in days {
+        if list_algorithms {
+            println!("Day {}: {}", day.number, day.algorithm_names().join(", "));
+            continue;
+        }
+        let mode_names = day.modes.iter().map(|mode| mode.name).collect::<Vec<_>>();
+        if list_modes {
+            match mode_names.len() {
+                0 => println!("Day {}: none", day.number),
+                _ => println!("Day {}: {}", day.number, mode_names.join(", ")),
+            }
+            continue;
+        }
+        let algorithm = algorithm.as_deref().unwrap_or(aoc::DEFAULT_ALGORITHM);
+        let Some(solvers) = day.solvers(algorithm) else {
+            println!(
+                "Day {} has no algorithm '{}', expected one of: {}",
+                day.number,
+                algorithm,
+                day.algorithm_names().join(", ")
+            );
+            continue;
+        };
+        let input = read_input(year, day, args.get(1));
+        let parameters = config.parameters(year.year, day.number);
+        if let Some(scale) = scale {
+            match aoc::scale::measure(day, &input, &parameters, scale) {
+                Some(measurements) => print!(
+                    "Day {}\n{}",
+                    day.number,
+                    aoc::scale::growth_table(&measurements)
+                ),
+                None => println!("Day {} can't be scaled", day.number),
+            }
+            continue;
+        }
+        if let Some(name) = &mode {
+            match day.modes.iter().find(|mode| mode.name == name) {
+                Some(mode) => match aoc::catch_panic(|| (mode.run)(&input, &parameters)) {
+                    Ok(output) => print!("{}", output),
+                    Err(message) => println!("Day {} {} failed: {}", day.number, name, message),
+                },
+                None if mode_names.is_empty() => println!("Day {} has no modes", day.number),
+                None => println!(
+                    "Day {} has no mode '{}', expected one of: {}",
+                    day.number,
+                    name,
+                    mode_names.join(", ")
+                ),
+            }
+            continue;
+        }
+        for (part, solver) in (1..).zip(solvers) {
+            match solve(solver, &input, &parameters) {
+                // Answers drawn over several lines start on a line of their own
+                Ok(solution) if solution.answer.contains('\n') => println!(
+                    "Day {} Part {} ({:?}):\n{}",
+                    day.number, part, solution.elapsed, solution.answer
+                ),
+                Ok(solution) => println!(
+                    "Day {} Part {}: {} ({:?})",
+                    day.number, part, solution.answer, solution.elapsed
+                ),
+                Err(message) => println!("Day {} Part {} failed: {}", day.number, part, message),
+            }
+            if let (true, Some(explainers)) = (explain, day.explain) {
+                let explainer = explainers[part as usize - 1];
+                match aoc::catch_panic(|| explainer(&input, &parameters)) {
+                    Ok(explanation) => println!("  {}", explanation),
+                    Err(message) => println!("  Could not explain: {}", message),
+                }
+            }
+        }
+        if explain && day.explain.is_none() {
+            println!("Day {} has no explanation", day.number);
+        }
+
+        let Some((path, output)) = &mut trace else {
+            continue;
+        };
+        let Some(tracers) = day.trace else {
+            println!("Day {} has no trace", day.number);
+            continue;
+        };
+        for (part, tracer) in (1..).zip(tracers) {
+            let mut lines = JsonLines::new(output, day.number, part);
+            let result =
+                aoc::catch_panic(AssertUnwindSafe(|| tracer(&input, &parameters, &mut lines)));
+            match (result, lines.finish()) {
+                (Ok(_), Ok(steps)) => println!(
+                    "Day {} Part {}: traced {} steps to {}",
+                    day.number, part, steps, path
+                ),
+                (Err(message), _) => {
+                    println!("Day {} Part {} failed: {}", day.number, part, message)
+                }
+                (_, Err(error)) => panic!("Could not write the trace to '{}'.\n{}", path, error),
+            }
+        }
+    }
+}
+
+// Times every algorithm of each day side by side
+fn bench(args: &[String]) {
+    let mut args = args.to_vec();
+    let runs = take_option(&mut args, "--runs").map_or(5, |runs| {
+        runs.parse::<usize>()
+            .unwrap_or_else(|_| panic!("{}", USAGE))
+    });
+    let (year, config, args) = configure(&args);
+    let days = if !args.is_empty() {
+        args.iter()
+            .map(|day| day_argument(year, Some(day)))
+            .collect()
+    } else {
+        year.days.iter().collect::<Vec<_>>()
+    };
+
+    for day in days {
+        let input = read_input(year, day, None);
+        let parameters = config.parameters(year.year, day.number);
+        let comparisons = aoc::bench::compare(day, &input, &parameters, runs);
+        print!("{}", aoc::bench::table(day.number, &comparisons));
+    }
+}
+
+fn repl(args: &[String]) {
+    let (year, config, args) = configure(args);
+    let day = day_argument(year, args.first());
+    let input = read_input(year, day, args.get(1));
+    let parameters = config.parameters(year.year, day.number);
+
+    println!(
+        "Loaded {} day {}, type 'help' for a list of commands",
+        year.year, day.number
+    );
+    aoc::repl::run(day, &input, &parameters, io::stdin().lock(), io::stdout()).unwrap();
+}
+
+fn print_profile_row(day: u8, phase: &str, usage: Option<Usage>) {
+    match usage {
+        Some(usage) => println!(
+            "{:>3}  {:<7}{:>12}{:>14}{:>14}",
+            day, phase, usage.allocations, usage.bytes_allocated, usage.peak_heap
+        ),
+        None => println!("{:>3}  {:<7}{:>12}{:>14}{:>14}", day, phase, "-", "-", "-"),
+    }
+}
+
+fn profile(args: &[String]) {
+    if !cfg!(feature = "profile") {
+        panic!("Allocations are only counted when built with `--features profile`");
+    }
+    let (year, config, args) = configure(args);
+    // Profile the given days, or every day when none are given
+    let days = if !args.is_empty() {
+        args.iter()
+            .map(|day| day_argument(year, Some(day)))
+            .collect()
+    } else {
+        year.days.iter().collect::<Vec<_>>()
+    };
+
+    println!(
+        "{:>3}  {:<7}{:>12}{:>14}{:>14}",
+        "Day", "Phase", "Allocations", "Bytes", "Peak heap"
+    );
+    for day in days {
+        let input = read_input(year, day, None);
+        let parameters = config.parameters(year.year, day.number);
+
+        let parse_usage = day
+            .parse
+            .map(|parse| measure(|| parse(&input, &parameters)).1);
+        print_profile_row(day.number, "parse", parse_usage);
+        let (_, part_1_usage) = measure(|| (day.part_1)(&input, &parameters));
+        print_profile_row(day.number, "part 1", Some(part_1_usage));
+        let (_, part_2_usage) = measure(|| (day.part_2)(&input, &parameters));
+        print_profile_row(day.number, "part 2", Some(part_2_usage));
+    }
+}
+
+fn examples(args: &[String]) {
+    let (year, _, args) = configure(args);
+    let day = day_argument(year, args.first());
+    let html = read_file(Path::new(
+        args.get(1).unwrap_or_else(|| panic!("{}", USAGE)),
+    ));
+
+    // Diagrams get run through the solver too, so keep their panics quiet
+    std::panic::set_hook(Box::new(|_| {}));
+    let (examples, warnings) = aoc::examples::extract(&html, day);
+    let _ = std::panic::take_hook();
+    for warning in warnings {
+        eprintln!("Warning: {}", warning);
+    }
+
+    let directory = year.day_directory(day.number);
+    common::examples::save(&directory, &examples).unwrap_or_else(|error| {
+        panic!(
+            "Could not write the examples of day {}.\n{}",
+            day.number, error
+        )
+    });
+    for example in examples {
+        println!(
+            "{}/examples/{}.txt: part 1 {}, part 2 {}",
+            directory.display(),
+            example.name,
+            example.part_1.as_deref().unwrap_or("-"),
+            example.part_2.as_deref().unwrap_or("-")
+        );
+    }
+}
+
+fn report(args: &[String]) {
+    let (year, config, args) = configure(args);
+    let directory = Path::new(args.first().map_or("report", |directory| directory));
+
+    let reports = year
+        .days
+        .iter()
+        .map(|day| {
+            println!("Running day {}", day.number);
+            aoc::report::run_day(year, day, &config)
+        })
+        .collect::<Vec<_>>();
+    aoc::report::write(directory, year.year, &reports).unwrap_or_else(|error| {
+        panic!(
+            "Could not write the report to '{}'.\n{}",
+            directory.display(),
+            error
+        )
+    });
+    println!("Wrote {}", directory.join("index.html").display());
+}
+
+fn load_solve_log() -> SolveLog {
+    SolveLog::load(Path::new(SOLVE_LOG))
+        .unwrap_or_else(|error| panic!("Could not read '{}'.\n{}", SOLVE_LOG, error))
+}
+
+// Checks the solver against an answer the site accepted, and records the
+// first time each part is verified in the solve log
+fn verify(args: &[String]) {
+    let (year, config, args) = configure(args);
+    let day = day_argument(year, args.first());
+    let (Some(part), Some(answer)) = (
+        args.get(1).and_then(|part| part.parse::<u8>().ok()),
+        args.get(2),
+    ) else {
+        panic!("{}", USAGE);
+    };
+    let solver = day.part(part).unwrap_or_else(|| panic!("{}", USAGE));
+    let input = read_input(year, day, args.get(3));
+    let parameters = config.parameters(year.year, day.number);
+
+    let solution = solve(solver, &input, &parameters)
+        .unwrap_or_else(|message| panic!("Day {} Part {} failed: {}", day.number, part, message));
+    // Drawn answers are read off the drawing by whoever submits them
+    if solution.answer.contains('\n') {
+        println!("{}", solution.answer);
+    } else if solution.answer != *answer {
+        println!(
+            "Day {} Part {}: the solver answers {}, not {}",
+            day.number, part, solution.answer, answer
+        );
+        std::process::exit(1);
+    }
+
+    if let Some(solve) = load_solve_log().first_solve(year.year, day.number, part) {
+        println!(
+            "Day {} Part {}: {} was already verified",
+            day.number, part, solve.answer
+        );
+        return;
+    }
+    let solve = Solve {
+        year: year.year,
+        day: day.number,
+        part,
+        solved_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs(),
+        answer: answer.clone(),
+    };
+    SolveLog::append(Path::new(SOLVE_LOG), &solve)
+        .unwrap_or_else(|error| panic!("Could not write to '{}'.\n{}", SOLVE_LOG, error));
+    println!(
+        "Day {} Part {}: {} verified, {} after the puzzle unlocked",
+        day.number,
+        part,
+        answer,
+        aoc::status::solved_after(&solve)
+    );
+}
+
+fn status(args: &[String]) {
+    let (year, config, _) = configure(args);
+    let log = load_solve_log();
+
+    println!("{}", aoc::status::calendar(year.year, &log));
+    // Every day with a solution or a verified answer
+    let statuses = (1..=25)
+        .filter(|&day| {
+            year.find_day(day).is_some()
+                || [1, 2]
+                    .iter()
+                    .any(|&part| log.first_solve(year.year, day, part).is_some())
+        })
+        .map(|day| aoc::status::check_day(year, day, &config))
+        .collect::<Vec<_>>();
+    print!("{}", aoc::status::table(year.year, &statuses, &log));
+}
+
+fn leaderboard(args: &[String]) {
+    let path = args.first().unwrap_or_else(|| panic!("{}", USAGE));
+    let csv = args.iter().any(|arg| arg == "--csv");
+    let view = args
+        .iter()
+        .skip(1)
+        .find(|arg| !arg.starts_with("--"))
+        .map_or("scores", |view| view);
+
+    let leaderboard = Leaderboard::from_json(&read_file(Path::new(path)))
+        .unwrap_or_else(|error| panic!("Could not read the leaderboard.\n{}", error));
+    let table = match view {
+        "scores" => leaderboard.scores_table(),
+        "stars" => leaderboard.stars_table(),
+        "deltas" => leaderboard.deltas_table(),
+        "days" => leaderboard.days_table(),
+        _ => panic!("{}", USAGE),
+    };
+    if csv {
+        print!("{}", table.to_csv());
+    } else {
+        print!("{}", table.to_text());
+    }
+}
+
+// Works for years that aren't registered yet, so doesn't go through
+// `configure`
+fn new(args: &[String]) {
+    let (year, day) = match args {
+        [year, day] => (
+            year.parse().unwrap_or_else(|_| panic!("{}", USAGE)),
+            day.parse().unwrap_or_else(|_| panic!("{}", USAGE)),
+        ),
+        _ => panic!("{}", USAGE),
+    };
+    let created = aoc::scaffold::create_day(Path::new("."), year, day)
+        .unwrap_or_else(|error| panic!("Could not create {} day {}.\n{}", year, day, error));
+    for path in created {
+        println!("Created {}", path.display());
+    }
+    println!("{}", aoc::scaffold::next_steps(year, day));
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    let rest = args.get(2..).unwrap_or_default();
+    match args.get(1).map(String::as_str) {
+        Some("run") => run(rest),
+        Some("bench") => bench(rest),
+        Some("repl") => repl(rest),
+        Some("profile") => profile(rest),
+        Some("examples") => examples(rest),
+        Some("report") => report(rest),
+        Some("verify") => verify(rest),
+        Some("status") => status(rest),
+        Some("leaderboard") => leaderboard(rest),
+        Some("new") => new(rest),
+        _ => println!("{}", USAGE),
+    }
+}
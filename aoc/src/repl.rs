@@ -0,0 +1,134 @@
+use std::{
+    io::{self, BufRead, Write},
+    panic::AssertUnwindSafe,
+};
+
+use common::explore::Explore;
+
+use crate::{catch_panic, config::Parameters, solve, Day};
+
+fn print_help(explorer: Option<&dyn Explore>, output: &mut impl Write) -> io::Result<()> {
+    writeln!(output, "  part1                 Solve part 1")?;
+    writeln!(output, "  part2                 Solve part 2")?;
+    if let Some(explorer) = explorer {
+        for command in explorer.commands() {
+            writeln!(output, "  {:<22}{}", command.usage, command.description)?;
+        }
+    }
+    writeln!(output, "  help                  Show this list")?;
+    writeln!(output, "  quit                  Leave the REPL")
+}
+
+pub fn run(
+    day: &Day,
+    input: &str,
+    parameters: &Parameters,
+    commands: impl BufRead,
+    mut output: impl Write,
+) -> io::Result<()> {
+    let mut explorer = match day.explore {
+        Some(explore) => match catch_panic(|| explore(input)) {
+            Ok(explorer) => Some(explorer),
+            Err(message) => {
+                writeln!(output, "Failed to parse the input: {}", message)?;
+                None
+            }
+        },
+        None => None,
+    };
+
+    let mut lines = commands.lines();
+    loop {
+        write!(output, "day {}> ", day.number)?;
+        output.flush()?;
+        let Some(line) = lines.next() else {
+            writeln!(output)?;
+            return Ok(());
+        };
+        let line = line?;
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        let Some((&command, arguments)) = words.split_first() else {
+            continue;
+        };
+
+        match command {
+            "quit" | "exit" => return Ok(()),
+            "help" => print_help(explorer.as_deref(), &mut output)?,
+            "part1" | "part2" => {
+                let solver = if command == "part1" {
+                    day.part_1
+                } else {
+                    day.part_2
+                };
+                match solve(solver, input, parameters) {
+                    Ok(solution) => {
+                        writeln!(output, "{} ({:?})", solution.answer, solution.elapsed)?
+                    }
+                    Err(message) => writeln!(output, "Error: {}", message)?,
+                }
+            }
+            _ => {
+                let result = explorer.as_mut().and_then(|explorer| {
+                    catch_panic(AssertUnwindSafe(|| explorer.execute(command, arguments)))
+                        .unwrap_or_else(|message| Some(Err(message)))
+                });
+                match result {
+                    Some(Ok(result)) => writeln!(output, "{}", result)?,
+                    Some(Err(message)) => writeln!(output, "Error: {}", message)?,
+                    None => writeln!(
+                        output,
+                        "Unknown command '{}', type 'help' for a list of commands",
+                        command
+                    )?,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::find_day;
+
+    fn run_commands(day: u8, input: &str, commands: &str) -> String {
+        let mut output = Vec::new();
+        run(
+            find_day(2022, day).unwrap(),
+            input,
+            &Parameters::default(),
+            commands.as_bytes(),
+            &mut output,
+        )
+        .unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn run_should_solve_both_parts() {
+        let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+        let output = run_commands(1, input, "part1\npart2\n");
+
+        assert!(output.contains("day 1> 24000 ("));
+        assert!(output.contains("day 1> 45000 ("));
+    }
+
+    #[test]
+    fn run_should_dispatch_day_specific_commands() {
+        let input = "$ cd /\n$ ls\ndir a\n14848514 b.txt\n$ cd a\n$ ls\n29116 f\n2557 g\n";
+        let output = run_commands(7, input, "du /a\ndu /\nls /\ndu /nope\n");
+
+        assert!(output.contains("day 7> 31673\n"));
+        assert!(output.contains("day 7> 14880187\n"));
+        assert!(output.contains("day 7> dir a\n14848514 in files\n"));
+        assert!(output.contains("day 7> Error: No such directory: '/nope'\n"));
+    }
+
+    #[test]
+    fn run_should_report_unknown_commands() {
+        let output = run_commands(1, "1000\n", "du /\nquit\npart1\n");
+
+        assert!(output.contains("Unknown command 'du'"));
+        assert!(!output.contains("1000"));
+    }
+}
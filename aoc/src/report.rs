@@ -0,0 +1,317 @@
+use std::{fmt::Write, fs, io, path::Path, time::Duration};
+
+use crate::{catch_panic, config::Config, solve, Day, Solution, Year};
+
+pub struct Visualization {
+    // Used in the file name, e.g. `day_10_crt.svg`
+    pub name: &'static str,
+    pub svg: String,
+}
+
+pub struct DayReport {
+    pub day: u8,
+    // `None` when the day has no input to run on
+    pub parts: Option<[Result<Solution, String>; 2]>,
+    // Examples whose recorded answers match, out of all recorded answers
+    pub examples: (usize, usize),
+    pub visualization: Option<Result<Visualization, String>>,
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// Lit pixels are drawn for every '#' of a screen drawn over several lines
+pub fn screen_svg(screen: &str) -> String {
+    const PIXEL: usize = 10;
+    let width = screen.lines().map(|line| line.len()).max().unwrap_or(0);
+    let height = screen.lines().count();
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\">\n<rect width=\"100%\" height=\"100%\" fill=\"#0f0f23\"/>\n",
+        width * PIXEL,
+        height * PIXEL
+    );
+    for (y, line) in screen.lines().enumerate() {
+        for (x, _) in line.chars().enumerate().filter(|&(_, pixel)| pixel == '#') {
+            writeln!(
+                svg,
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"#ffff66\"/>",
+                x * PIXEL,
+                y * PIXEL,
+                PIXEL,
+                PIXEL
+            )
+            .unwrap();
+        }
+    }
+    svg + "</svg>\n"
+}
+
+// Draws the path as a line, with y pointing up
+pub fn path_svg(path: &[(i32, i32)]) -> String {
+    const SCALE: i32 = 4;
+    let min_x = path.iter().map(|&(x, _)| x).min().unwrap_or(0);
+    let max_x = path.iter().map(|&(x, _)| x).max().unwrap_or(0);
+    let min_y = path.iter().map(|&(_, y)| y).min().unwrap_or(0);
+    let max_y = path.iter().map(|&(_, y)| y).max().unwrap_or(0);
+    let points = path
+        .iter()
+        .map(|&(x, y)| format!("{},{}", (x - min_x + 1) * SCALE, (max_y - y + 1) * SCALE))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\">\n<rect width=\"100%\" height=\"100%\" fill=\"#0f0f23\"/>\n<polyline points=\"{}\" fill=\"none\" stroke=\"#00cc00\" stroke-width=\"1\"/>\n</svg>\n",
+        (max_x - min_x + 2) * SCALE,
+        (max_y - min_y + 2) * SCALE,
+        points
+    )
+}
+
+// Shades every square of a heightmap by its height, 'a' (and 'S') darkest
+// and 'z' (and 'E') brightest, and draws the path over it
+pub fn heightmap_svg(heightmap: &str, path: &[(usize, usize)]) -> String {
+    const SQUARE: usize = 6;
+    let rows = heightmap
+        .lines()
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>();
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\">\n",
+        width * SQUARE,
+        rows.len() * SQUARE
+    );
+    for (y, row) in rows.iter().enumerate() {
+        for (x, square) in row.chars().enumerate() {
+            let height = match square {
+                'S' => 0,
+                'E' => 25,
+                square => (square as u8).saturating_sub(b'a').min(25) as usize,
+            };
+            writeln!(
+                svg,
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"hsl(120,40%,{}%)\"/>",
+                x * SQUARE,
+                y * SQUARE,
+                SQUARE,
+                SQUARE,
+                10 + height * 3
+            )
+            .unwrap();
+        }
+    }
+    let points = path
+        .iter()
+        .map(|&(y, x)| format!("{},{}", x * SQUARE + SQUARE / 2, y * SQUARE + SQUARE / 2))
+        .collect::<Vec<_>>()
+        .join(" ");
+    writeln!(
+        svg,
+        "<polyline points=\"{}\" fill=\"none\" stroke=\"#ff3333\" stroke-width=\"2\"/>",
+        points
+    )
+    .unwrap();
+    svg + "</svg>\n"
+}
+
+pub fn check_examples(year: &Year, day: &Day, config: &Config) -> (usize, usize) {
+    let parameters = config.parameters(year.year, day.number);
+    let mut passed = 0;
+    let mut total = 0;
+    for example in common::examples::load(year.day_directory(day.number)) {
+        for (solver, answer) in [(day.part_1, &example.part_1), (day.part_2, &example.part_2)] {
+            let Some(answer) = answer else {
+                continue;
+            };
+            total += 1;
+            if solve(solver, &example.input, &parameters)
+                .is_ok_and(|solution| &solution.answer == answer)
+            {
+                passed += 1;
+            }
+        }
+    }
+    (passed, total)
+}
+
+pub fn run_day(year: &Year, day: &Day, config: &Config) -> DayReport {
+    let parameters = config.parameters(year.year, day.number);
+    let input = year.read_input(day).ok();
+    DayReport {
+        day: day.number,
+        parts: input.as_ref().map(|input| {
+            [
+                solve(day.part_1, input, &parameters),
+                solve(day.part_2, input, &parameters),
+            ]
+        }),
+        examples: check_examples(year, day, config),
+        visualization: input.as_ref().and_then(|input| {
+            day.visualize
+                .map(|visualize| catch_panic(|| visualize(input, &parameters)))
+        }),
+    }
+}
+
+fn visualization_file(report: &DayReport, visualization: &Visualization) -> String {
+    format!("day_{}_{}.svg", report.day, visualization.name)
+}
+
+pub fn render_html(year: u16, reports: &[DayReport]) -> String {
+    let slowest = reports
+        .iter()
+        .flat_map(|report| report.parts.iter().flatten())
+        .filter_map(|part| part.as_ref().ok())
+        .map(|solution| solution.elapsed)
+        .max()
+        .unwrap_or(Duration::ZERO)
+        .max(Duration::from_nanos(1));
+
+    let mut html = format!(
+        "<!DOCTYPE html>
+<html>
+<head>
+<meta charset=\"utf-8\">
+<title>Advent of Code {0}</title>
+<style>
+body {{ background: #0f0f23; color: #cccccc; font-family: monospace; }}
+table {{ border-collapse: collapse; }}
+th, td {{ padding: 4px 12px; text-align: left; vertical-align: top; }}
+tr:nth-child(even) {{ background: #1a1a38; }}
+pre {{ margin: 0; }}
+a {{ color: #00cc00; }}
+.bar {{ background: #00cc00; height: 8px; margin: 2px 0; }}
+.failed {{ color: #ff3333; }}
+.passed {{ color: #00cc00; }}
+</style>
+</head>
+<body>
+<h1>Advent of Code {0}</h1>
+<table>
+<tr><th>Day</th><th>Part 1</th><th>Part 2</th><th>Time</th><th>Examples</th><th>Visualization</th></tr>
+",
+        year
+    );
+
+    for report in reports {
+        write!(html, "<tr><td>{}</td>", report.day).unwrap();
+        match &report.parts {
+            Some(parts) => {
+                for part in parts {
+                    match part {
+                        Ok(solution) if solution.answer.contains('\n') => {
+                            write!(html, "<td><pre>{}</pre></td>", escape(&solution.answer))
+                        }
+                        Ok(solution) => write!(html, "<td>{}</td>", escape(&solution.answer)),
+                        Err(message) => {
+                            write!(html, "<td class=\"failed\">{}</td>", escape(message))
+                        }
+                    }
+                    .unwrap();
+                }
+                html += "<td>";
+                for (part, solution) in parts.iter().enumerate() {
+                    if let Ok(solution) = solution {
+                        write!(
+                            html,
+                            "<div class=\"bar\" style=\"width: {:.1}%\"></div>Part {}: {:?}<br>",
+                            solution.elapsed.as_secs_f64() / slowest.as_secs_f64() * 100.0,
+                            part + 1,
+                            solution.elapsed
+                        )
+                        .unwrap();
+                    }
+                }
+                html += "</td>";
+            }
+            None => html += "<td colspan=\"3\" class=\"failed\">No input</td>",
+        }
+
+        match report.examples {
+            (_, 0) => html += "<td>No examples</td>",
+            (passed, total) => write!(
+                html,
+                "<td class=\"{}\">{}/{} passed</td>",
+                if passed == total { "passed" } else { "failed" },
+                passed,
+                total
+            )
+            .unwrap(),
+        }
+
+        match &report.visualization {
+            Some(Ok(visualization)) => {
+                let file = visualization_file(report, visualization);
+                write!(html, "<td><a href=\"{0}\">{0}</a></td>", file).unwrap()
+            }
+            Some(Err(message)) => {
+                write!(html, "<td class=\"failed\">{}</td>", escape(message)).unwrap()
+            }
+            None => html += "<td></td>",
+        }
+        html += "</tr>\n";
+    }
+    html + "</table>\n</body>\n</html>\n"
+}
+
+// Writes `index.html` and the SVG files it links to into `directory`
+pub fn write(directory: &Path, year: u16, reports: &[DayReport]) -> io::Result<()> {
+    fs::create_dir_all(directory)?;
+    for report in reports {
+        if let Some(Ok(visualization)) = &report.visualization {
+            fs::write(
+                directory.join(visualization_file(report, visualization)),
+                &visualization.svg,
+            )?;
+        }
+    }
+    fs::write(directory.join("index.html"), render_html(year, reports))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn screen_svg_should_draw_a_pixel_for_every_lit_pixel() {
+        let svg = screen_svg("#..\n.##\n");
+
+        assert!(svg.contains("width=\"30\" height=\"20\""));
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"10\""));
+        assert!(svg.contains("<rect x=\"10\" y=\"10\" width=\"10\""));
+        assert!(svg.contains("<rect x=\"20\" y=\"10\" width=\"10\""));
+        assert_eq!(svg.matches("fill=\"#ffff66\"").count(), 3);
+    }
+
+    #[test]
+    fn render_html_should_escape_answers_and_link_visualizations() {
+        let reports = [DayReport {
+            day: 10,
+            parts: Some([
+                Ok(Solution {
+                    answer: "<1>".to_string(),
+                    elapsed: Duration::from_millis(2),
+                }),
+                Err("Bad input".to_string()),
+            ]),
+            examples: (1, 2),
+            visualization: Some(Ok(Visualization {
+                name: "crt",
+                svg: String::new(),
+            })),
+        }];
+        let html = render_html(2022, &reports);
+
+        assert!(html.contains("<td>&lt;1&gt;</td>"));
+        assert!(html.contains("<td class=\"failed\">Bad input</td>"));
+        assert!(html.contains("style=\"width: 100.0%\""));
+        assert!(html.contains("<td class=\"failed\">1/2 passed</td>"));
+        assert!(html.contains("<a href=\"day_10_crt.svg\">day_10_crt.svg</a>"));
+    }
+}
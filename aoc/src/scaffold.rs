@@ -0,0 +1,157 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+// The files of a new day crate in `<year>/day_<day>`, relative to the
+// repository root
+pub fn day_files(year: u16, day: u8) -> Vec<(PathBuf, String)> {
+    let directory = Path::new(&year.to_string()).join(format!("day_{}", day));
+    let name = format!("y{}_day_{}", year, day);
+    let manifest = format!(
+        "[package]
+name = \"{name}\"
+version = \"0.1.0\"
+edition = \"2021\"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[features]
+# Builds the puzzle input into the crate as `INPUT`
+embed-input = []
+
+[dependencies]
+common = {{ path = \"../../common\" }}
+"
+    );
+    let lib = "#[cfg(feature = \"embed-input\")]
+pub const INPUT: Option<&str> = Some(include_str!(\"../input.txt\"));
+#[cfg(not(feature = \"embed-input\"))]
+pub const INPUT: Option<&str> = None;
+
+pub fn part_1(input: &str) -> u64 {
+    todo!(\"Part 1 of {} lines\", input.lines().count())
+}
+
+pub fn part_2(input: &str) -> u64 {
+    todo!(\"Part 2 of {} lines\", input.lines().count())
+}
+"
+    .to_string();
+    let main = format!(
+        "use {name}::{{part_1, part_2, INPUT}};
+
+fn main() {{
+    let args: Vec<String> = std::env::args().collect();
+    let input = match (args.get(1), INPUT) {{
+        (Some(path), _) => std::fs::read_to_string(path)
+            .unwrap_or_else(|error| panic!(\"No file found at: '{{}}'.\\n{{}}\", path, error)),
+        (None, Some(input)) => input.to_string(),
+        (None, None) => panic!(\"Expected the path to the puzzle input\"),
+    }};
+
+    let part_1_result = part_1(&input);
+    println!(\"Day {day} Part 1: {{}}\", part_1_result);
+
+    let part_2_result = part_2(&input);
+    println!(\"Day {day} Part 2: {{}}\", part_2_result);
+}}
+"
+    );
+
+    vec![
+        (directory.join("Cargo.toml"), manifest),
+        (directory.join("src/lib.rs"), lib),
+        (directory.join("src/main.rs"), main),
+        (directory.join("input.txt"), String::new()),
+    ]
+}
+
+// Writes the day's files under `root`, refusing to touch a day that is
+// already there
+pub fn create_day(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Expected a day from 1 to 25, found {}", day));
+    }
+    let files = day_files(year, day);
+    let directory = root.join(files[0].0.parent().unwrap());
+    if directory.exists() {
+        return Err(format!("'{}' already exists", directory.display()));
+    }
+
+    let mut created = Vec::new();
+    for (path, contents) in files {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap())
+            .and_then(|_| fs::write(&path, contents))
+            .map_err(|error| format!("Could not write '{}'.\n{}", path.display(), error))?;
+        created.push(path);
+    }
+    Ok(created)
+}
+
+// What still has to be added by hand for the runner to know the new day
+pub fn next_steps(year: u16, day: u8) -> String {
+    let name = format!("y{}_day_{}", year, day);
+    format!(
+        "Then, to run it with `aoc`:
+- add \"{year}/day_*\" to the workspace members in Cargo.toml if it's a new year
+- add `{name} = {{ path = \"../{year}/day_{day}\" }}` to the dependencies in aoc/Cargo.toml
+- add \"{name}/embed-input\" to the `embed-inputs` feature in aoc/Cargo.toml
+- save the puzzle's examples with `aoc examples {year} {day} <saved puzzle page>`,
+  as `cargo test -p aoc` checks every registered day has some
+- add `use {name} as day_{day};` and this entry to `DAYS` in aoc/src/year_{year}.rs,
+  creating it and adding it to `YEARS` in aoc/src/lib.rs if it's a new year:
+
+    Day {{
+        number: {day},
+        parse: None,
+        part_1: |input, _| day_{day}::part_1(input).to_string(),
+        part_2: |input, _| day_{day}::part_2(input).to_string(),
+        parameters: &[],
+        algorithms: &[],
+        modes: &[],
+        explore: None,
+        explain: None,
+        trace: None,
+        scale: None,
+        input: day_{day}::INPUT,
+        visualize: None,
+    }},"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_files_should_name_the_crate_after_the_year() {
+        let files = day_files(2023, 4);
+
+        assert_eq!(files[0].0, Path::new("2023/day_4/Cargo.toml"));
+        assert!(files[0].1.contains("name = \"y2023_day_4\""));
+        assert!(files[2]
+            .1
+            .starts_with("use y2023_day_4::{part_1, part_2, INPUT};"));
+        assert!(files[2]
+            .1
+            .contains("println!(\"Day 4 Part 1: {}\", part_1_result);"));
+    }
+
+    #[test]
+    fn create_day_should_refuse_a_day_that_exists() {
+        let root = std::env::temp_dir().join(format!("scaffold_test_{}", std::process::id()));
+
+        let created = create_day(&root, 2023, 1).unwrap();
+        assert_eq!(created.len(), 4);
+        assert!(root.join("2023/day_1/src/lib.rs").exists());
+        assert_eq!(
+            create_day(&root, 2023, 1).unwrap_err(),
+            format!("'{}' already exists", root.join("2023/day_1").display())
+        );
+        assert!(create_day(&root, 2023, 26).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
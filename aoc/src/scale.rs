@@ -0,0 +1,125 @@
+use std::time::Duration;
+
+use crate::{config::Parameters, solve, Day};
+
+// For days whose input stays valid when repeated
+pub fn repeat(input: &str, factor: usize, separator: &str) -> String {
+    vec![input.trim_end(); factor].join(separator) + "\n"
+}
+
+// 1, 10, 100, ... up to `max`, ending with `max` itself
+pub fn scales(max: usize) -> Vec<usize> {
+    let mut scales = Vec::new();
+    let mut scale = 1;
+    while scale < max {
+        scales.push(scale);
+        scale *= 10;
+    }
+    scales.push(max.max(1));
+    scales
+}
+
+// The k for which the run time grows like n^k between two measurements, where
+// n is the size of the input, e.g. 1 for a solver that's linear in its input
+pub fn growth_exponent(from: (usize, Duration), to: (usize, Duration)) -> f64 {
+    let time_ratio = to.1.as_secs_f64() / from.1.as_secs_f64().max(f64::MIN_POSITIVE);
+    let size_ratio = to.0 as f64 / from.0 as f64;
+    time_ratio.ln() / size_ratio.ln()
+}
+
+pub struct Measurement {
+    pub scale: usize,
+    pub bytes: usize,
+    pub parts: [Result<Duration, String>; 2],
+}
+
+pub fn measure(
+    day: &Day,
+    input: &str,
+    parameters: &Parameters,
+    max_scale: usize,
+) -> Option<Vec<Measurement>> {
+    let scale_input = day.scale?;
+    Some(
+        scales(max_scale)
+            .into_iter()
+            .map(|scale| {
+                let input = scale_input(input, scale);
+                let time =
+                    |solver| solve(solver, &input, parameters).map(|solution| solution.elapsed);
+                Measurement {
+                    scale,
+                    bytes: input.len(),
+                    parts: [time(day.part_1), time(day.part_2)],
+                }
+            })
+            .collect(),
+    )
+}
+
+pub fn growth_table(measurements: &[Measurement]) -> String {
+    let mut table = format!(
+        "{:>8}{:>14}{:>16}{:>10}{:>16}{:>10}\n",
+        "Scale", "Bytes", "Part 1", "Growth", "Part 2", "Growth"
+    );
+    for (index, measurement) in measurements.iter().enumerate() {
+        table += &format!("{:>8}{:>14}", measurement.scale, measurement.bytes);
+        for part in 0..2 {
+            let growth = index
+                .checked_sub(1)
+                .map(|previous| &measurements[previous])
+                .and_then(
+                    |previous| match (&previous.parts[part], &measurement.parts[part]) {
+                        // Not every day's input grows exactly by the scale
+                        (Ok(from), Ok(to)) => Some(growth_exponent(
+                            (previous.bytes, *from),
+                            (measurement.bytes, *to),
+                        )),
+                        _ => None,
+                    },
+                );
+            match (&measurement.parts[part], growth) {
+                (Ok(elapsed), Some(growth)) => {
+                    table += &format!(
+                        "{:>16}{:>10}",
+                        format!("{:?}", elapsed),
+                        format!("n^{:.2}", growth)
+                    )
+                }
+                (Ok(elapsed), None) => {
+                    table += &format!("{:>16}{:>10}", format!("{:?}", elapsed), "-")
+                }
+                (Err(_), _) => table += &format!("{:>16}{:>10}", "failed", "-"),
+            }
+        }
+        table += "\n";
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scales_should_grow_tenfold_up_to_the_maximum() {
+        assert_eq!(scales(1000), vec![1, 10, 100, 1000]);
+        assert_eq!(scales(250), vec![1, 10, 100, 250]);
+        assert_eq!(scales(1), vec![1]);
+    }
+
+    #[test]
+    fn growth_exponent_should_be_the_power_the_time_grows_by() {
+        let linear = growth_exponent(
+            (1, Duration::from_millis(2)),
+            (10, Duration::from_millis(20)),
+        );
+        let quadratic = growth_exponent(
+            (10, Duration::from_millis(1)),
+            (100, Duration::from_millis(100)),
+        );
+
+        assert!((linear - 1.0).abs() < 1e-9);
+        assert!((quadratic - 2.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,242 @@
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    thread,
+    time::Duration,
+};
+
+use common::json;
+
+use crate::{config::Parameters, find_year, latest_year, solve};
+
+// Puzzle inputs are tens of kilobytes, so anything much bigger isn't one
+const MAX_BODY: usize = 1 << 20;
+const MAX_HEADERS: u64 = 16 << 10;
+// So a client that stops sending doesn't hold on to its thread
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+struct Request {
+    method: String,
+    path: String,
+    body: String,
+}
+
+struct Response {
+    status: &'static str,
+    body: String,
+}
+
+impl Response {
+    fn error(status: &'static str, message: &str) -> Response {
+        Response {
+            status,
+            body: format!("{{\"error\":{}}}", json::string(message)),
+        }
+    }
+}
+
+fn read_request(stream: &mut TcpStream) -> Result<Request, Response> {
+    let malformed = || Response::error("400 Bad Request", "Malformed HTTP request");
+    let mut reader = BufReader::new(stream.take(MAX_HEADERS + MAX_BODY as u64));
+    let mut request_line = String::new();
+    reader
+        .read_line(&mut request_line)
+        .map_err(|_| malformed())?;
+    let mut request_line = request_line.split_whitespace();
+    let method = request_line.next().ok_or_else(malformed)?.to_string();
+    let path = request_line.next().ok_or_else(malformed)?.to_string();
+
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        // Ends without a newline when the headers run past the limit
+        if reader.read_line(&mut header).map_err(|_| malformed())? == 0 || !header.ends_with('\n') {
+            return Err(malformed());
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().map_err(|_| malformed())?;
+            }
+        }
+    }
+    if content_length > MAX_BODY {
+        return Err(Response::error(
+            "413 Content Too Large",
+            &format!("The input can be at most {} bytes", MAX_BODY),
+        ));
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).map_err(|_| malformed())?;
+
+    Ok(Request {
+        method,
+        path,
+        body: String::from_utf8(body).map_err(|_| malformed())?,
+    })
+}
+
+// Without a year in the path the latest year is used
+fn parse_path(path: &str) -> Option<(Option<u16>, u8, u8)> {
+    match path.split('/').collect::<Vec<_>>()[..] {
+        ["", "day", day, "part", part] => Some((None, day.parse().ok()?, part.parse().ok()?)),
+        ["", year, "day", day, "part", part] => Some((
+            Some(year.parse().ok()?),
+            day.parse().ok()?,
+            part.parse().ok()?,
+        )),
+        _ => None,
+    }
+}
+
+fn handle(request: &Request) -> Response {
+    let Some((year, day_number, part)) = parse_path(&request.path) else {
+        return Response::error("404 Not Found", "Expected /{year}/day/{n}/part/{p}");
+    };
+    if request.method != "POST" {
+        return Response::error("405 Method Not Allowed", "Only POST is supported");
+    }
+    let Some(year) = year.map_or(Some(latest_year()), find_year) else {
+        return Response::error("404 Not Found", "Year is not solved");
+    };
+    let Some(day) = year.find_day(day_number) else {
+        return Response::error(
+            "404 Not Found",
+            &format!("Day {} is not solved", day_number),
+        );
+    };
+    let Some(solver) = day.part(part) else {
+        return Response::error(
+            "404 Not Found",
+            &format!("Day {} has no part {}", day_number, part),
+        );
+    };
+
+    match solve(solver, &request.body, &Parameters::default()) {
+        Ok(solution) => Response {
+            status: "200 OK",
+            body: format!(
+                "{{\"year\":{},\"day\":{},\"part\":{},\"answer\":{},\"elapsed_ns\":{}}}",
+                year.year,
+                day_number,
+                part,
+                json::string(&solution.answer),
+                solution.elapsed.as_nanos()
+            ),
+        },
+        Err(message) => Response::error(
+            "422 Unprocessable Entity",
+            &format!(
+                "Failed to solve day {} part {}: {}",
+                day_number, part, message
+            ),
+        ),
+    }
+}
+
+fn handle_connection(mut stream: TcpStream) {
+    let request = stream
+        .set_read_timeout(Some(READ_TIMEOUT))
+        .map_err(|error| Response::error("500 Internal Server Error", &error.to_string()))
+        .and_then(|()| read_request(&mut stream));
+    let response = match request {
+        Ok(request) => handle(&request),
+        Err(response) => response,
+    };
+
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.body.len(),
+        response.body
+    );
+}
+
+pub fn serve(listener: TcpListener) {
+    for stream in listener.incoming().flatten() {
+        thread::spawn(|| handle_connection(stream));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn start_server() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        thread::spawn(|| serve(listener));
+        address
+    }
+
+    fn send(address: &str, method: &str, path: &str, body: &str) -> String {
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(
+            stream,
+            "{} {} HTTP/1.1\r\nHost: {}\r\nContent-Length: {}\r\n\r\n{}",
+            method,
+            path,
+            address,
+            body.len(),
+            body
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn post_should_return_the_answer_as_json() {
+        let address = start_server();
+        let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+        let response = send(&address, "POST", "/2022/day/1/part/2", input);
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response
+            .contains("{\"year\":2022,\"day\":1,\"part\":2,\"answer\":\"45000\",\"elapsed_ns\":"));
+
+        let response = send(&address, "POST", "/day/1/part/1", input);
+        assert!(response.contains("\"year\":2022,\"day\":1,\"part\":1,\"answer\":\"24000\""));
+    }
+
+    #[test]
+    fn post_should_return_an_error_when_the_input_cannot_be_parsed() {
+        let address = start_server();
+
+        let response = send(&address, "POST", "/day/7/part/1", "$ cd /\n$ ls\nfoo bar\n");
+        assert!(response.starts_with("HTTP/1.1 422 Unprocessable Entity"));
+        assert!(response.contains("{\"error\":\"Failed to solve day 7 part 1: "));
+    }
+
+    #[test]
+    fn unknown_routes_should_return_not_found() {
+        let address = start_server();
+
+        assert!(send(&address, "POST", "/day/26/part/1", "").starts_with("HTTP/1.1 404"));
+        assert!(send(&address, "POST", "/2015/day/1/part/1", "").starts_with("HTTP/1.1 404"));
+        assert!(send(&address, "POST", "/day/1/part/3", "").starts_with("HTTP/1.1 404"));
+        assert!(send(&address, "POST", "/days", "").starts_with("HTTP/1.1 404"));
+        assert!(send(&address, "GET", "/day/1/part/1", "").starts_with("HTTP/1.1 405"));
+    }
+
+    #[test]
+    fn bodies_over_the_limit_should_be_rejected_before_reading_them() {
+        let address = start_server();
+        let mut stream = TcpStream::connect(&address).unwrap();
+        write!(
+            stream,
+            "POST /day/1/part/1 HTTP/1.1\r\nContent-Length: 1000000000000\r\n\r\n"
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 413 Content Too Large"));
+    }
+}
@@ -0,0 +1,233 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    time::Duration,
+};
+
+use crate::{
+    config::Config,
+    leaderboard::{days_from_civil, format_duration, unlock_timestamp},
+    report::check_examples,
+    solve, Solution, Year,
+};
+
+// Where `verify` records the answers that were accepted
+pub const SOLVE_LOG: &str = "solves.log";
+
+// One line of the log: year, day, part, seconds since the epoch and the
+// answer, separated by tabs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solve {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub solved_at: u64,
+    pub answer: String,
+}
+
+#[derive(Debug, Default)]
+pub struct SolveLog {
+    solves: Vec<Solve>,
+}
+
+impl SolveLog {
+    pub fn parse(text: &str) -> Result<SolveLog, String> {
+        let solves = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                let fields = line.splitn(5, '\t').collect::<Vec<_>>();
+                let solve = match fields[..] {
+                    [year, day, part, solved_at, answer] => (|| {
+                        Some(Solve {
+                            year: year.parse().ok()?,
+                            day: day.parse().ok()?,
+                            part: part.parse().ok()?,
+                            solved_at: solved_at.parse().ok()?,
+                            answer: answer.to_string(),
+                        })
+                    })(),
+                    _ => None,
+                };
+                solve.ok_or_else(|| {
+                    format!(
+                        "Line {}: expected the year, day, part, time and answer, found '{}'",
+                        index + 1,
+                        line
+                    )
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(SolveLog { solves })
+    }
+
+    // A missing log is an empty one
+    pub fn load(path: &Path) -> Result<SolveLog, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => SolveLog::parse(&text),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(SolveLog::default()),
+            Err(error) => Err(error.to_string()),
+        }
+    }
+
+    pub fn append(path: &Path, solve: &Solve) -> io::Result<()> {
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(
+            file,
+            "{}\t{}\t{}\t{}\t{}",
+            solve.year, solve.day, solve.part, solve.solved_at, solve.answer
+        )
+    }
+
+    pub fn first_solve(&self, year: u16, day: u8, part: u8) -> Option<&Solve> {
+        self.solves
+            .iter()
+            .filter(|solve| (solve.year, solve.day, solve.part) == (year, day, part))
+            .min_by_key(|solve| solve.solved_at)
+    }
+}
+
+// How long after its puzzle unlocked a part was solved
+pub fn solved_after(solve: &Solve) -> String {
+    let unlock = unlock_timestamp(i32::from(solve.year), solve.day);
+    format_duration((solve.solved_at as i64 - unlock).max(0))
+}
+
+// December laid out by week, with a star for every part that was solved:
+//
+//   Mo  Tu  We  Th  Fr  Sa  Su
+//               1** 2** 3*  4
+pub fn calendar(year: u16, log: &SolveLog) -> String {
+    // 1970-01-01 was a Thursday
+    let first_weekday = (days_from_civil(i64::from(year), 12, 1) + 3) % 7;
+    let mut calendar = format!("December {}\n Mo  Tu  We  Th  Fr  Sa  Su\n", year);
+    let mut cells = vec!["    ".to_string(); first_weekday as usize];
+    for day in 1..=25 {
+        let stars = [1, 2]
+            .iter()
+            .filter(|&&part| log.first_solve(year, day, part).is_some())
+            .count();
+        cells.push(format!("{:>2}{:<2}", day, "*".repeat(stars)));
+    }
+    for week in cells.chunks(7) {
+        calendar += week.join("").trim_end();
+        calendar += "\n";
+    }
+    calendar
+}
+
+pub struct DayStatus {
+    pub day: u8,
+    pub parts: [Option<Result<Solution, String>>; 2],
+    pub examples: (usize, usize),
+}
+
+pub fn check_day(year: &Year, day: u8, config: &Config) -> DayStatus {
+    let Some(solution) = year.find_day(day) else {
+        return DayStatus {
+            day,
+            parts: [None, None],
+            examples: (0, 0),
+        };
+    };
+    let parameters = config.parameters(year.year, day);
+    let input = year.read_input(solution).ok();
+    let part = |part| {
+        input
+            .as_ref()
+            .map(|input| solve(solution.part(part).unwrap(), input, &parameters))
+    };
+    DayStatus {
+        day,
+        parts: [part(1), part(2)],
+        examples: check_examples(year, solution, config),
+    }
+}
+
+// How what the solver answers compares to the answer that was accepted
+fn answer_status(
+    solution: &Option<Result<Solution, String>>,
+    solve: Option<&Solve>,
+) -> &'static str {
+    match (solution, solve) {
+        (None, _) => "-",
+        (Some(Err(_)), _) => "failed",
+        (Some(Ok(_)), None) => "unsolved",
+        // Drawn answers are read off the drawing, so can't be compared
+        (Some(Ok(solution)), Some(_)) if solution.answer.contains('\n') => "drawn",
+        (Some(Ok(solution)), Some(solve)) if solution.answer == solve.answer => "ok",
+        (Some(Ok(_)), Some(_)) => "wrong",
+    }
+}
+
+pub fn table(year: u16, statuses: &[DayStatus], log: &SolveLog) -> String {
+    let mut table = format!(
+        "{:>3}  {:<10}{:<10}{:>12}{:>10}{:>16}{:>16}\n",
+        "Day", "Part 1", "Part 2", "Runtime", "Examples", "Part 1 after", "Part 2 after"
+    );
+    for status in statuses {
+        let solves = [1, 2].map(|part| log.first_solve(year, status.day, part));
+        let runtime = status
+            .parts
+            .iter()
+            .map(|part| match part {
+                Some(Ok(solution)) => Some(solution.elapsed),
+                _ => None,
+            })
+            .sum::<Option<Duration>>();
+        let solved_after = solves.map(|solve| solve.map_or("-".to_string(), solved_after));
+        table += &format!(
+            "{:>3}  {:<10}{:<10}{:>12}{:>10}{:>16}{:>16}\n",
+            status.day,
+            answer_status(&status.parts[0], solves[0]),
+            answer_status(&status.parts[1], solves[1]),
+            runtime.map_or("-".to_string(), |runtime| format!("{:.2?}", runtime)),
+            match status.examples {
+                (_, 0) => "-".to_string(),
+                (passed, total) => format!("{}/{}", passed, total),
+            },
+            solved_after[0],
+            solved_after[1],
+        );
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solve_log_should_keep_the_first_solve_of_each_part() {
+        let log = SolveLog::parse("2022\t1\t1\t200\t67622\n2022\t1\t1\t100\t67622\n").unwrap();
+
+        assert_eq!(log.first_solve(2022, 1, 1).unwrap().solved_at, 100);
+        assert_eq!(log.first_solve(2022, 1, 2), None);
+        assert_eq!(
+            SolveLog::parse("2022\t1\n").unwrap_err(),
+            "Line 1: expected the year, day, part, time and answer, found '2022\t1'"
+        );
+    }
+
+    #[test]
+    fn calendar_should_start_on_the_weekday_of_december_first() {
+        let log = SolveLog::parse(&format!(
+            "2022\t1\t1\t{}\t1\n2022\t1\t2\t{}\t2\n2022\t2\t1\t{}\t3\n",
+            unlock_timestamp(2022, 1) + 60,
+            unlock_timestamp(2022, 1) + 120,
+            unlock_timestamp(2022, 2) + 60
+        ))
+        .unwrap();
+        let calendar = calendar(2022, &log);
+
+        // December 2022 started on a Thursday
+        assert_eq!(calendar.lines().nth(2), Some("             1** 2*  3   4"));
+        assert_eq!(calendar.lines().last(), Some("19  20  21  22  23  24  25"));
+        assert_eq!(
+            solved_after(log.first_solve(2022, 1, 2).unwrap()),
+            "00:02:00"
+        );
+    }
+}
@@ -0,0 +1,636 @@
+use std::{collections::HashSet, fs};
+
+use crate::{
+    config::Parameters, explain, report, report::Visualization, scale, Algorithm, Day, Mode,
+    Parameter,
+};
+use y2022_day_1 as day_1;
+use y2022_day_10 as day_10;
+use y2022_day_11 as day_11;
+use y2022_day_12 as day_12;
+use y2022_day_2 as day_2;
+use y2022_day_3 as day_3;
+use y2022_day_4 as day_4;
+use y2022_day_5 as day_5;
+use y2022_day_6 as day_6;
+use y2022_day_7 as day_7;
+use y2022_day_8 as day_8;
+use y2022_day_9 as day_9;
+
+fn explain_elves(ranking: &day_1::Ranking) -> String {
+    let describe = |elf: &day_1::Elf| {
+        format!(
+            "#{} elf {} ({} calories in {})",
+            elf.rank,
+            elf.number,
+            elf.calories,
+            explain::count(elf.items, "item")
+        )
+    };
+    let top = ranking.top.iter().map(describe).collect::<Vec<_>>();
+    let mut explanation = format!(
+        "The {} carrying the most: {}",
+        if top.len() == 1 { "elf" } else { "elves" },
+        explain::list(&top)
+    );
+    if let Some(last) = ranking.top.last().filter(|_| !ranking.tied_out.is_empty()) {
+        let tied = ranking
+            .tied_out
+            .iter()
+            .map(|elf| elf.number.to_string())
+            .collect::<Vec<_>>();
+        explanation += &format!(
+            "\n  {} {} also {} {} calories, but {} after elf {} in the input",
+            if tied.len() == 1 { "Elf" } else { "Elves" },
+            explain::list(&tied),
+            if tied.len() == 1 { "carries" } else { "carry" },
+            last.calories,
+            if tied.len() == 1 { "comes" } else { "come" },
+            last.number
+        );
+    }
+    explanation
+}
+
+fn explain_monkey_business(business: &day_11::MonkeyBusiness) -> String {
+    let monkeys = business
+        .most_active
+        .iter()
+        .map(|(monkey, inspections)| format!("monkey {} ({} inspections)", monkey, inspections))
+        .collect::<Vec<_>>();
+    format!(
+        "The most active are {}, making {}",
+        explain::list(&monkeys),
+        business.level
+    )
+}
+
+// `game` is the path of a definition like `day_2::ROCK_PAPER_SCISSORS`, whose
+// scores the other parameters override
+fn day_2_game(parameters: &Parameters) -> day_2::Game {
+    let path = parameters.get("game", String::new());
+    let mut game = if path.is_empty() {
+        day_2::Game::default()
+    } else {
+        let definition = fs::read_to_string(&path)
+            .unwrap_or_else(|error| panic!("Could not read the game '{}'.\n{}", path, error));
+        day_2::Game::parse(&definition).unwrap_or_else(|error| panic!("{}: {}", path, error))
+    };
+
+    let values = parameters.get("shape_values", String::new());
+    if !values.is_empty() {
+        game.set_values(&values)
+            .unwrap_or_else(|error| panic!("Invalid shape_values '{}'. {}", values, error));
+    }
+    for (name, outcome) in [
+        ("lose_points", day_2::Outcome::Lose),
+        ("draw_points", day_2::Outcome::Draw),
+        ("win_points", day_2::Outcome::Win),
+    ] {
+        let points = parameters.get(name, game.outcomes[outcome as usize].1);
+        game.set_points(outcome, points);
+    }
+    game
+}
+
+// A line per mapping, most first, noting the ones scoring the most and the
+// least and the one the game defines
+fn describe_mappings<T: PartialEq>(
+    meaning: &str,
+    symbols: &[String],
+    defined: &[T],
+    mappings: &[day_2::Mapping<T>],
+    name: impl Fn(&T) -> String,
+) -> String {
+    let mut description = format!("Reading {} as {}:\n", explain::list(symbols), meaning);
+    let (most, least) = (mappings[0].score, mappings[mappings.len() - 1].score);
+    for mapping in mappings {
+        let meanings = symbols
+            .iter()
+            .zip(&mapping.meanings)
+            .map(|(symbol, meaning)| format!("{} {}", symbol, name(meaning)))
+            .collect::<Vec<_>>();
+        let notes = [
+            (mapping.score == most, "most"),
+            (mapping.score == least, "least"),
+            (mapping.meanings == defined, "as defined"),
+        ]
+        .iter()
+        .filter(|(applies, _)| *applies)
+        .map(|(_, note)| *note)
+        .collect::<Vec<_>>();
+        description += &format!("  {}: {}", meanings.join(", "), mapping.score);
+        if !notes.is_empty() {
+            description += &format!(" ({})", notes.join(", "));
+        }
+        description += "\n";
+    }
+    description
+}
+
+fn day_2_mappings(input: &str, parameters: &Parameters) -> String {
+    let game = day_2_game(parameters);
+    let shape_symbols = game
+        .shapes
+        .iter()
+        .map(|shape| shape.symbol.clone())
+        .collect::<Vec<_>>();
+    let outcome_symbols = game
+        .outcomes
+        .iter()
+        .map(|(symbol, _)| symbol.clone())
+        .collect::<Vec<_>>();
+    describe_mappings(
+        "shapes",
+        &shape_symbols,
+        &(0..game.shapes.len()).collect::<Vec<_>>(),
+        &game.shape_mappings(input),
+        |&shape| game.shapes[shape].name.clone(),
+    ) + &describe_mappings(
+        "outcomes",
+        &outcome_symbols,
+        &[
+            day_2::Outcome::Lose,
+            day_2::Outcome::Draw,
+            day_2::Outcome::Win,
+        ],
+        &game.outcome_mappings(input),
+        |outcome| format!("{:?}", outcome).to_lowercase(),
+    )
+}
+
+fn day_2_rounds(input: &str, parameters: &Parameters) -> String {
+    let game = day_2_game(parameters);
+    format!(
+        "Part 1\n{}\nPart 2\n{}",
+        game.breakdown_part_1(input).text(&game),
+        game.breakdown_part_2(input).text(&game)
+    )
+}
+
+fn day_2_rounds_csv(input: &str, parameters: &Parameters) -> String {
+    let game = day_2_game(parameters);
+    day_2::BREAKDOWN_CSV_HEADER.to_string()
+        + &game.breakdown_part_1(input).csv(&game, 1)
+        + &game.breakdown_part_2(input).csv(&game, 2)
+}
+
+fn day_2_tournament(input: &str, parameters: &Parameters) -> String {
+    let game = day_2_game(parameters);
+    let opponent = parameters.get("opponent", day_2::Opponent::Uniform);
+    let games = parameters.get("games", 1000);
+    let seed = parameters.get("seed", 2022);
+    let tournament = game.simulate(input, &opponent, games, seed);
+    format!(
+        "{} games of {} against {} (seed {})\n{}",
+        tournament.games,
+        explain::count(tournament.rounds, "round"),
+        opponent,
+        seed,
+        tournament
+    )
+}
+
+fn day_1_plan(input: &str, parameters: &Parameters) -> String {
+    let elves = parameters.get("plan_elves", day_1::split_input(input).len());
+    day_1::plan_redistribution(input, elves).to_string()
+}
+
+pub static DAYS: [Day; 12] = [
+    Day {
+        number: 1,
+        trace: None,
+        scale: Some(|input, factor| scale::repeat(input, factor, "\n\n")),
+        input: day_1::INPUT,
+        parse: Some(|input, _| {
+            day_1::split_input(input);
+        }),
+        part_1: |input, _| day_1::part_1(input).to_string(),
+        part_2: |input, parameters| {
+            let k = parameters.get("top_elves", day_1::TOP_ELVES);
+            day_1::top_k(input, k).iter().sum::<u64>().to_string()
+        },
+        parameters: &[
+            Parameter::parsed::<usize>("top_elves"),
+            Parameter::positive::<usize>("plan_elves"),
+        ],
+        algorithms: &[],
+        modes: &[
+            Mode {
+                name: "stats",
+                run: |input, _| {
+                    day_1::statistics(input)
+                        .map_or("No elves to describe\n".to_string(), |statistics| {
+                            statistics.to_string()
+                        })
+                },
+            },
+            Mode {
+                name: "plan",
+                run: day_1_plan,
+            },
+        ],
+        explain: Some([
+            |input, _| explain_elves(&day_1::top_elves(input, 1)),
+            |input, parameters| {
+                let k = parameters.get("top_elves", day_1::TOP_ELVES);
+                explain_elves(&day_1::top_elves(input, k))
+            },
+        ]),
+        explore: None,
+        visualize: None,
+    },
+    Day {
+        number: 2,
+        trace: None,
+        scale: Some(|input, factor| scale::repeat(input, factor, "\n")),
+        input: day_2::INPUT,
+        parse: Some(|input, parameters| {
+            day_2_game(parameters).shapes_played(input);
+        }),
+        part_1: |input, parameters| day_2_game(parameters).part_1(input).to_string(),
+        part_2: |input, parameters| day_2_game(parameters).part_2(input).to_string(),
+        parameters: &[
+            Parameter::text("game"),
+            Parameter::text("shape_values"),
+            Parameter::parsed::<u16>("lose_points"),
+            Parameter::parsed::<u16>("draw_points"),
+            Parameter::parsed::<u16>("win_points"),
+            Parameter::parsed::<day_2::Opponent>("opponent"),
+            Parameter::positive::<usize>("games"),
+            Parameter::parsed::<u64>("seed"),
+        ],
+        algorithms: &[],
+        modes: &[
+            Mode {
+                name: "mappings",
+                run: day_2_mappings,
+            },
+            Mode {
+                name: "rounds",
+                run: day_2_rounds,
+            },
+            Mode {
+                name: "rounds-csv",
+                run: day_2_rounds_csv,
+            },
+            Mode {
+                name: "tournament",
+                run: day_2_tournament,
+            },
+        ],
+        explain: None,
+        explore: None,
+        visualize: None,
+    },
+    Day {
+        number: 3,
+        trace: None,
+        scale: Some(|input, factor| scale::repeat(input, factor, "\n")),
+        input: day_3::INPUT,
+        parse: None,
+        part_1: |input, _| day_3::part_1(input).to_string(),
+        part_2: |input, _| day_3::part_2(input).to_string(),
+        parameters: &[],
+        algorithms: &[],
+        modes: &[],
+        explain: None,
+        explore: None,
+        visualize: None,
+    },
+    Day {
+        number: 4,
+        trace: None,
+        scale: Some(|input, factor| scale::repeat(input, factor, "\n")),
+        input: day_4::INPUT,
+        parse: None,
+        part_1: |input, _| day_4::part_1(input).to_string(),
+        part_2: |input, _| day_4::part_2(input).to_string(),
+        parameters: &[],
+        algorithms: &[],
+        modes: &[],
+        explain: None,
+        explore: None,
+        visualize: None,
+    },
+    Day {
+        number: 5,
+        trace: Some([
+            |input, _, trace| day_5::part_1_traced(input, trace),
+            |input, _, trace| day_5::part_2_traced(input, trace),
+        ]),
+        scale: Some(day_5::scale_input),
+        input: day_5::INPUT,
+        parse: Some(|input, _| {
+            day_5::Cargo::new(input);
+        }),
+        part_1: |input, _| day_5::part_1(input),
+        part_2: |input, _| day_5::part_2(input),
+        parameters: &[],
+        algorithms: &[],
+        modes: &[],
+        explain: None,
+        explore: Some(|input| Box::new(day_5::Cargo::new(input))),
+        visualize: None,
+    },
+    Day {
+        number: 6,
+        trace: None,
+        scale: Some(day_6::scale_input),
+        input: day_6::INPUT,
+        parse: None,
+        part_1: |input, parameters| {
+            let window = parameters.get("packet_window", day_6::PACKET_WINDOW);
+            day_6::find_marker(input, window).to_string()
+        },
+        part_2: |input, parameters| {
+            let window = parameters.get("message_window", day_6::MESSAGE_WINDOW);
+            day_6::find_marker(input, window).to_string()
+        },
+        parameters: &[
+            Parameter::positive::<usize>("packet_window"),
+            Parameter::positive::<usize>("message_window"),
+        ],
+        algorithms: &[Algorithm {
+            name: "bitmask",
+            part_1: |input, parameters| {
+                let window = parameters.get("packet_window", day_6::PACKET_WINDOW);
+                day_6::find_marker_bitmask(input, window).to_string()
+            },
+            part_2: |input, parameters| {
+                let window = parameters.get("message_window", day_6::MESSAGE_WINDOW);
+                day_6::find_marker_bitmask(input, window).to_string()
+            },
+        }],
+        modes: &[],
+        explain: None,
+        explore: None,
+        visualize: None,
+    },
+    Day {
+        number: 7,
+        trace: None,
+        scale: Some(day_7::scale_input),
+        input: day_7::INPUT,
+        parse: Some(|input, _| {
+            day_7::FileSystem::new(input);
+        }),
+        part_1: |input, _| day_7::part_1(input).to_string(),
+        part_2: |input, parameters| {
+            day_7::find_directory_to_delete(
+                input,
+                parameters.get("total_space", day_7::TOTAL_SPACE),
+                parameters.get("required_space", day_7::REQUIRED_SPACE),
+            )
+            .to_string()
+        },
+        parameters: &[
+            Parameter::parsed::<u32>("total_space"),
+            Parameter::parsed::<u32>("required_space"),
+        ],
+        algorithms: &[],
+        modes: &[],
+        explain: Some([
+            |input, _| {
+                let mut directories = day_7::small_directories(input);
+                directories.sort_by_key(|&(_, size)| std::cmp::Reverse(size));
+                match directories.first() {
+                    Some((path, size)) => format!(
+                        "{} directories hold at most 100000, the biggest being {} with {}",
+                        directories.len(),
+                        path,
+                        size
+                    ),
+                    None => "No directory holds at most 100000".to_string(),
+                }
+            },
+            |input, parameters| {
+                let deletion = day_7::choose_directory_to_delete(
+                    input,
+                    parameters.get("total_space", day_7::TOTAL_SPACE),
+                    parameters.get("required_space", day_7::REQUIRED_SPACE),
+                );
+                match deletion.path {
+                    Some(path) => format!(
+                        "The update needs {} more space, and the smallest directory freeing that up is {} with {}",
+                        deletion.space_needed, path, deletion.size
+                    ),
+                    None => "There's already enough free space for the update".to_string(),
+                }
+            },
+        ]),
+        explore: Some(|input| Box::new(day_7::FileSystem::new(input))),
+        visualize: None,
+    },
+    Day {
+        number: 8,
+        trace: None,
+        scale: Some(day_8::scale_input),
+        input: day_8::INPUT,
+        parse: Some(|input, _| {
+            day_8::create_map(input);
+        }),
+        part_1: |input, _| day_8::part_1(input).to_string(),
+        part_2: |input, _| day_8::part_2(input).to_string(),
+        parameters: &[],
+        algorithms: &[Algorithm {
+            name: "monotonic-stack",
+            part_1: |input, _| day_8::part_1_monotonic_stack(input).to_string(),
+            part_2: |input, _| day_8::part_2_monotonic_stack(input).to_string(),
+        }],
+        modes: &[],
+        explain: None,
+        explore: None,
+        visualize: None,
+    },
+    Day {
+        number: 9,
+        trace: Some([
+            |input, _, trace| {
+                // Part 1 is the same as a rope of two knots
+                let path = day_9::tail_path_traced(input, 2, trace);
+                path.into_iter().collect::<HashSet<_>>().len().to_string()
+            },
+            |input, parameters, trace| {
+                let knots = parameters.get("knots", day_9::KNOTS);
+                let path = day_9::tail_path_traced(input, knots, trace);
+                path.into_iter().collect::<HashSet<_>>().len().to_string()
+            },
+        ]),
+        scale: Some(|input, factor| scale::repeat(input, factor, "\n")),
+        input: day_9::INPUT,
+        parse: None,
+        part_1: |input, _| day_9::part_1(input).to_string(),
+        part_2: |input, parameters| {
+            day_9::count_tail_positions(input, parameters.get("knots", day_9::KNOTS)).to_string()
+        },
+        parameters: &[Parameter::positive::<usize>("knots")],
+        algorithms: &[],
+        modes: &[],
+        explain: None,
+        explore: None,
+        visualize: Some(|input, parameters| Visualization {
+            name: "rope",
+            svg: report::path_svg(&day_9::tail_path(
+                input,
+                parameters.get("knots", day_9::KNOTS),
+            )),
+        }),
+    },
+    Day {
+        number: 10,
+        trace: Some([
+            |input, _, trace| day_10::part_1_traced(input, trace).to_string(),
+            |input, parameters, trace| {
+                day_10::render_traced(
+                    input,
+                    parameters.get("screen_width", day_10::SCREEN_WIDTH),
+                    parameters.get("screen_height", day_10::SCREEN_HEIGHT),
+                    trace,
+                )
+            },
+        ]),
+        scale: Some(|input, factor| scale::repeat(input, factor, "\n")),
+        input: day_10::INPUT,
+        parse: None,
+        part_1: |input, _| day_10::part_1(input).to_string(),
+        part_2: |input, parameters| {
+            day_10::render(
+                input,
+                parameters.get("screen_width", day_10::SCREEN_WIDTH),
+                parameters.get("screen_height", day_10::SCREEN_HEIGHT),
+            )
+        },
+        parameters: &[
+            Parameter::positive::<usize>("screen_width"),
+            Parameter::positive::<usize>("screen_height"),
+        ],
+        algorithms: &[],
+        modes: &[],
+        explain: None,
+        explore: None,
+        visualize: Some(|input, parameters| Visualization {
+            name: "crt",
+            svg: report::screen_svg(&day_10::render(
+                input,
+                parameters.get("screen_width", day_10::SCREEN_WIDTH),
+                parameters.get("screen_height", day_10::SCREEN_HEIGHT),
+            )),
+        }),
+    },
+    Day {
+        number: 11,
+        trace: Some([
+            |input, parameters, trace| {
+                day_11::monkey_business_traced(
+                    input,
+                    parameters.get("part_1_rounds", day_11::PART_1_ROUNDS),
+                    parameters.get("relief", day_11::RELIEF),
+                    trace,
+                )
+                .level
+                .to_string()
+            },
+            |input, parameters, trace| {
+                day_11::monkey_business_traced(
+                    input,
+                    parameters.get("part_2_rounds", day_11::PART_2_ROUNDS),
+                    1,
+                    trace,
+                )
+                .level
+                .to_string()
+            },
+        ]),
+        scale: Some(day_11::scale_input),
+        input: day_11::INPUT,
+        parse: Some(|input, _| {
+            day_11::Monkeys::new(input);
+        }),
+        part_1: |input, parameters| {
+            day_11::monkey_business(
+                input,
+                parameters.get("part_1_rounds", day_11::PART_1_ROUNDS),
+                parameters.get("relief", day_11::RELIEF),
+            )
+            .level
+            .to_string()
+        },
+        part_2: |input, parameters| {
+            day_11::monkey_business(
+                input,
+                parameters.get("part_2_rounds", day_11::PART_2_ROUNDS),
+                1,
+            )
+            .level
+            .to_string()
+        },
+        parameters: &[
+            Parameter::parsed::<usize>("part_1_rounds"),
+            Parameter::parsed::<usize>("part_2_rounds"),
+            Parameter::positive::<u64>("relief"),
+        ],
+        algorithms: &[],
+        modes: &[],
+        explain: Some([
+            |input, parameters| {
+                explain_monkey_business(&day_11::monkey_business(
+                    input,
+                    parameters.get("part_1_rounds", day_11::PART_1_ROUNDS),
+                    parameters.get("relief", day_11::RELIEF),
+                ))
+            },
+            |input, parameters| {
+                explain_monkey_business(&day_11::monkey_business(
+                    input,
+                    parameters.get("part_2_rounds", day_11::PART_2_ROUNDS),
+                    1,
+                ))
+            },
+        ]),
+        explore: Some(|input| Box::new(day_11::Monkeys::new(input))),
+        visualize: None,
+    },
+    Day {
+        number: 12,
+        trace: None,
+        scale: Some(day_12::scale_input),
+        input: day_12::INPUT,
+        parse: Some(|input, _| {
+            day_12::Heightmap::new(input);
+        }),
+        part_1: |input, _| day_12::part_1(input).to_string(),
+        part_2: |input, _| day_12::part_2(input).to_string(),
+        parameters: &[],
+        algorithms: &[Algorithm {
+            name: "reverse-bfs",
+            part_1: |input, _| day_12::part_1_reverse_bfs(input).to_string(),
+            part_2: |input, _| day_12::part_2_reverse_bfs(input).to_string(),
+        }],
+        modes: &[],
+        explain: Some([
+            |input, _| match day_12::shortest_path(input) {
+                Some(path) => format!(
+                    "The route from S at {} to E at {} takes {} steps",
+                    explain::square(path[0]),
+                    explain::square(path[path.len() - 1]),
+                    path.len() - 1
+                ),
+                None => "There is no route from S to E".to_string(),
+            },
+            |input, _| {
+                let route = day_12::best_start(input);
+                format!(
+                    "Starting from {} takes {} steps, the fewest of any lowest square",
+                    explain::square(route.start),
+                    route.steps
+                )
+            },
+        ]),
+        explore: Some(|input| Box::new(day_12::Heightmap::new(input))),
+        visualize: Some(|input, _| Visualization {
+            name: "hill",
+            svg: report::heightmap_svg(input, &day_12::shortest_path(input).unwrap_or_default()),
+        }),
+    },
+];
@@ -0,0 +1,30 @@
+// A CSV line of `cells`, quoting the ones that hold a comma, a quote or a
+// line break
+pub fn row<S: AsRef<str>>(cells: &[S]) -> String {
+    cells
+        .iter()
+        .map(|cell| {
+            let cell = cell.as_ref();
+            if cell.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", cell.replace('"', "\"\""))
+            } else {
+                cell.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn row_should_quote_cells_that_need_it() {
+        assert_eq!(row(&["a", "b c", ""]), "a,b c,");
+        assert_eq!(
+            row(&["Doe, Jane", "say \"hi\"", "two\nlines"]),
+            "\"Doe, Jane\",\"say \"\"hi\"\"\",\"two\nlines\""
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use std::{fs, io, path::Path};
+
+// Examples are stored in a day's `examples` directory as `<name>.txt` holding
+// the input and `<name>.answers` holding a `part_1: ...` and/or `part_2: ...`
+// line for each part the example has an answer for.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+pub fn load(day_directory: impl AsRef<Path>) -> Vec<Example> {
+    let directory = day_directory.as_ref().join("examples");
+    let Ok(entries) = fs::read_dir(&directory) else {
+        return Vec::new();
+    };
+
+    let mut examples = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+        .map(|path| {
+            let name = path.file_stem().unwrap().to_string_lossy().to_string();
+            let input = fs::read_to_string(&path)
+                .unwrap_or_else(|error| panic!("Could not read '{}'.\n{}", path.display(), error));
+            let answers = fs::read_to_string(path.with_extension("answers")).unwrap_or_default();
+            let answer = |part: &str| {
+                answers.lines().find_map(|line| {
+                    line.strip_prefix(part)
+                        .and_then(|answer| answer.strip_prefix(':'))
+                        .map(|answer| answer.trim().to_string())
+                })
+            };
+            Example {
+                part_1: answer("part_1"),
+                part_2: answer("part_2"),
+                name,
+                input,
+            }
+        })
+        .collect::<Vec<_>>();
+    examples.sort_by_key(|example| {
+        (
+            example.name.parse::<u32>().unwrap_or(u32::MAX),
+            example.name.clone(),
+        )
+    });
+    examples
+}
+
+pub fn save(day_directory: impl AsRef<Path>, examples: &[Example]) -> io::Result<()> {
+    let directory = day_directory.as_ref().join("examples");
+    fs::create_dir_all(&directory)?;
+    for example in examples {
+        fs::write(
+            directory.join(format!("{}.txt", example.name)),
+            &example.input,
+        )?;
+        let mut answers = String::new();
+        if let Some(answer) = &example.part_1 {
+            answers += &format!("part_1: {}\n", answer);
+        }
+        if let Some(answer) = &example.part_2 {
+            answers += &format!("part_2: {}\n", answer);
+        }
+        fs::write(directory.join(format!("{}.answers", example.name)), answers)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_should_return_the_saved_examples() {
+        let directory = std::env::temp_dir().join(format!("examples_test_{}", std::process::id()));
+        let examples = vec![
+            Example {
+                name: "1".to_string(),
+                input: "1000\n\n2000\n".to_string(),
+                part_1: Some("2000".to_string()),
+                part_2: Some("3000".to_string()),
+            },
+            Example {
+                name: "2".to_string(),
+                input: "R 5\n".to_string(),
+                part_1: None,
+                part_2: Some("36".to_string()),
+            },
+        ];
+
+        save(&directory, &examples).unwrap();
+        let loaded = load(&directory);
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(loaded, examples);
+    }
+
+    #[test]
+    fn load_should_return_nothing_without_an_examples_directory() {
+        assert!(load("/does/not/exist").is_empty());
+    }
+}
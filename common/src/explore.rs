@@ -0,0 +1,12 @@
+pub struct Command {
+    pub usage: &'static str,
+    pub description: &'static str,
+}
+
+// Implemented by a day's parsed input so it can be inspected from the REPL
+// without parsing the input again for every command.
+pub trait Explore {
+    fn commands(&self) -> &'static [Command];
+
+    fn execute(&mut self, command: &str, arguments: &[&str]) -> Option<Result<String, String>>;
+}
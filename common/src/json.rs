@@ -0,0 +1,35 @@
+use std::fmt;
+
+// Writes `string` quoted, escaping what JSON doesn't allow in a string
+pub fn write_string(writer: &mut impl fmt::Write, string: &str) -> fmt::Result {
+    writer.write_char('"')?;
+    for character in string.chars() {
+        match character {
+            '"' => writer.write_str("\\\"")?,
+            '\\' => writer.write_str("\\\\")?,
+            '\n' => writer.write_str("\\n")?,
+            '\r' => writer.write_str("\\r")?,
+            '\t' => writer.write_str("\\t")?,
+            character if character.is_control() => write!(writer, "\\u{:04x}", character as u32)?,
+            character => writer.write_char(character)?,
+        }
+    }
+    writer.write_char('"')
+}
+
+pub fn string(string: &str) -> String {
+    let mut result = String::with_capacity(string.len() + 2);
+    write_string(&mut result, string).unwrap();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn string_should_escape_special_characters() {
+        assert_eq!(string("a\"b\\c\nd"), "\"a\\\"b\\\\c\\nd\"");
+        assert_eq!(string("\r\t\u{1}é"), "\"\\r\\t\\u0001é\"");
+    }
+}
@@ -0,0 +1,6 @@
+pub mod csv;
+pub mod examples;
+pub mod explore;
+pub mod json;
+pub mod parse;
+pub mod trace;
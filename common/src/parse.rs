@@ -0,0 +1,310 @@
+use std::{fmt, str::FromStr};
+
+// Where parsing failed, counted from 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "Line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+// Reads the input from front to back. Grammars are plain functions taking a
+// `&mut Parser`, and the methods that take a grammar (`separated`, `lines`,
+// `blocks`) combine them into bigger ones:
+//
+//   parse(input, |parser| parser.blocks(|parser| parser.lines(Parser::number)))
+//
+// Parsers are `Copy`, so a grammar can try something on a copy and carry on
+// from the original when it doesn't match.
+#[derive(Debug, Clone, Copy)]
+pub struct Parser<'a> {
+    rest: &'a str,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(input: &'a str) -> Parser<'a> {
+        Parser {
+            rest: input,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> Error {
+        Error {
+            line: self.line,
+            column: self.column,
+            message: message.into(),
+        }
+    }
+
+    fn expected(&self, expected: &str) -> Error {
+        let found = match self.rest.lines().next() {
+            None => "the end of the input".to_string(),
+            Some("") => "the end of the line".to_string(),
+            Some(line) if line.chars().count() > 20 => {
+                format!("'{}...'", line.chars().take(20).collect::<String>())
+            }
+            Some(line) => format!("'{}'", line.trim_end_matches('\r')),
+        };
+        self.error(format!("Expected {}, found {}", expected, found))
+    }
+
+    fn advance(&mut self, length: usize) -> &'a str {
+        let (taken, rest) = self.rest.split_at(length);
+        for character in taken.chars() {
+            if character == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+        }
+        self.rest = rest;
+        taken
+    }
+
+    pub fn at_end(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    pub fn peek(&self, expected: &str) -> bool {
+        self.rest.starts_with(expected)
+    }
+
+    pub fn at_line_end(&self) -> bool {
+        self.at_end() || self.peek("\n") || self.peek("\r\n")
+    }
+
+    // Whether the rest of the line is empty or only spaces and tabs
+    pub fn at_blank_line(&self) -> bool {
+        let mut parser = *self;
+        parser.spaces();
+        parser.at_line_end()
+    }
+
+    pub fn spaces(&mut self) {
+        let length = self.rest.len() - self.rest.trim_start_matches([' ', '\t']).len();
+        self.advance(length);
+    }
+
+    pub fn blank_line(&mut self) -> Result<()> {
+        self.spaces();
+        if !self.at_line_end() {
+            return Err(self.expected("a blank line"));
+        }
+        self.line_end()
+    }
+
+    pub fn literal(&mut self, expected: &str) -> Result<()> {
+        if !self.peek(expected) {
+            return Err(self.expected(&format!("'{}'", expected)));
+        }
+        self.advance(expected.len());
+        Ok(())
+    }
+
+    // The value of the first option whose text comes next
+    pub fn one_of<T: Copy>(&mut self, options: &[(&str, T)]) -> Result<T> {
+        for &(text, value) in options {
+            if self.peek(text) {
+                self.advance(text.len());
+                return Ok(value);
+            }
+        }
+        let texts = options
+            .iter()
+            .map(|(text, _)| format!("'{}'", text))
+            .collect::<Vec<_>>();
+        Err(self.expected(&format!("one of {}", texts.join(", "))))
+    }
+
+    // Digits, with a minus sign in front for negative numbers
+    pub fn number<T: FromStr>(&mut self) -> Result<T> {
+        let sign = usize::from(self.peek("-"));
+        let digits = self.rest[sign..]
+            .find(|character: char| !character.is_ascii_digit())
+            .unwrap_or(self.rest.len() - sign);
+        if digits == 0 {
+            return Err(self.expected("a number"));
+        }
+
+        let start = *self;
+        let number = self.advance(sign + digits);
+        number
+            .parse()
+            .map_err(|_| start.error(format!("'{}' is out of range", number)))
+    }
+
+    // Everything up to the next space or the end of the line
+    pub fn word(&mut self) -> Result<&'a str> {
+        let length = self
+            .rest
+            .find(|character: char| character.is_whitespace())
+            .unwrap_or(self.rest.len());
+        if length == 0 {
+            return Err(self.expected("a word"));
+        }
+        Ok(self.advance(length))
+    }
+
+    // For lines that are drawings rather than grammars
+    pub fn rest_of_line(&mut self) -> Result<&'a str> {
+        let length = self.rest.find('\n').unwrap_or(self.rest.len());
+        let line = self.advance(length);
+        Ok(line.strip_suffix('\r').unwrap_or(line))
+    }
+
+    pub fn line_end(&mut self) -> Result<()> {
+        if self.peek("\r\n") {
+            self.advance(2);
+        } else if self.peek("\n") {
+            self.advance(1);
+        } else if !self.at_end() {
+            return Err(self.expected("the end of the line"));
+        }
+        Ok(())
+    }
+
+    // Only blank lines may be left
+    pub fn end(&mut self) -> Result<()> {
+        let length = self.rest.len() - self.rest.trim_start().len();
+        self.advance(length);
+        if !self.at_end() {
+            return Err(self.expected("the end of the input"));
+        }
+        Ok(())
+    }
+
+    // One or more items with `separator` between them
+    pub fn separated<T>(
+        &mut self,
+        separator: &str,
+        mut item: impl FnMut(&mut Parser<'a>) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let mut items = vec![item(self)?];
+        while self.peek(separator) {
+            self.advance(separator.len());
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+
+    // One item per line, up to a blank line or the end of the input
+    pub fn lines<T>(
+        &mut self,
+        mut line: impl FnMut(&mut Parser<'a>) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        while !self.at_blank_line() {
+            items.push(line(self)?);
+            self.line_end()?;
+        }
+        Ok(items)
+    }
+
+    // Items separated by blank lines, each spanning one or more lines and
+    // ending with the end of its last line
+    pub fn blocks<T>(
+        &mut self,
+        mut block: impl FnMut(&mut Parser<'a>) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        loop {
+            items.push(block(self)?);
+            let mut blank_lines = 0;
+            while !self.at_end() && self.at_blank_line() {
+                self.blank_line()?;
+                blank_lines += 1;
+            }
+            if self.at_end() {
+                return Ok(items);
+            }
+            if blank_lines == 0 {
+                return Err(self.expected("a blank line"));
+            }
+        }
+    }
+}
+
+// Runs a grammar over the whole input
+pub fn parse<'a, T>(
+    input: &'a str,
+    grammar: impl FnOnce(&mut Parser<'a>) -> Result<T>,
+) -> Result<T> {
+    let mut parser = Parser::new(input);
+    let result = grammar(&mut parser)?;
+    parser.end()?;
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blocks_should_split_on_blank_lines() {
+        let blocks = parse("1\n2\n\n3\r\n\r\n\n4\n\n", |parser| {
+            parser.blocks(|parser| parser.lines(Parser::number::<u32>))
+        });
+
+        assert_eq!(blocks, Ok(vec![vec![1, 2], vec![3], vec![4]]));
+    }
+
+    #[test]
+    fn blocks_should_treat_lines_of_spaces_and_tabs_as_blank() {
+        let blocks = parse("1\n \t\n2\r\n  \r\n\t\n3\n", |parser| {
+            parser.blocks(|parser| parser.lines(Parser::number::<u32>))
+        });
+
+        assert_eq!(blocks, Ok(vec![vec![1], vec![2], vec![3]]));
+    }
+
+    #[test]
+    fn separated_should_parse_every_item() {
+        let numbers = parse("-1, 2, 30", |parser| {
+            parser.separated(", ", Parser::number::<i32>)
+        });
+
+        assert_eq!(numbers, Ok(vec![-1, 2, 30]));
+    }
+
+    #[test]
+    fn errors_should_point_at_the_line_and_column() {
+        let error = parse("move 1 from 2 to 3\nmove x from 2 to 3\n", |parser| {
+            parser.lines(|parser| {
+                parser.literal("move ")?;
+                let count = parser.number::<usize>()?;
+                parser.literal(" from ")?;
+                parser.separated(" to ", Parser::number::<usize>)?;
+                Ok(count)
+            })
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Line 2, column 6: Expected a number, found 'x from 2 to 3'"
+        );
+        assert_eq!(
+            parse("256", |parser| parser.number::<u8>())
+                .unwrap_err()
+                .message,
+            "'256' is out of range"
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use std::{
+    fmt,
+    io::{self, Write},
+};
+
+use crate::json;
+
+// A snapshot of a simulation's state, written out as JSON
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(i64),
+    Text(String),
+    List(Vec<Value>),
+    Object(Vec<(&'static str, Value)>),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Null => write!(formatter, "null"),
+            Value::Bool(value) => write!(formatter, "{}", value),
+            Value::Number(value) => write!(formatter, "{}", value),
+            Value::Text(value) => json::write_string(formatter, value),
+            Value::List(values) => {
+                write!(formatter, "[")?;
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        write!(formatter, ",")?;
+                    }
+                    write!(formatter, "{}", value)?;
+                }
+                write!(formatter, "]")
+            }
+            Value::Object(fields) => {
+                write!(formatter, "{{")?;
+                for (index, (name, value)) in fields.iter().enumerate() {
+                    if index > 0 {
+                        write!(formatter, ",")?;
+                    }
+                    json::write_string(formatter, name)?;
+                    write!(formatter, ":{}", value)?;
+                }
+                write!(formatter, "}}")
+            }
+        }
+    }
+}
+
+// Simulations call `step` with the state after every step. The state is only
+// built when it's going to be recorded, and `()` records nothing, so the
+// untraced solvers don't pay for it.
+pub trait Trace {
+    fn step(&mut self, state: impl FnOnce() -> Value);
+}
+
+impl Trace for () {
+    fn step(&mut self, _: impl FnOnce() -> Value) {}
+}
+
+// Keeps the states in memory, e.g. to look at them in tests
+impl Trace for Vec<Value> {
+    fn step(&mut self, state: impl FnOnce() -> Value) {
+        self.push(state());
+    }
+}
+
+// Writes one line per step:
+//
+//   {"day":9,"part":2,"step":0,"state":{...}}
+//
+// Write errors are kept until `finish` so the simulations don't have to deal
+// with them.
+pub struct JsonLines<'a> {
+    output: &'a mut dyn Write,
+    day: u8,
+    part: u8,
+    step: usize,
+    error: Option<io::Error>,
+}
+
+impl<'a> JsonLines<'a> {
+    pub fn new(output: &'a mut dyn Write, day: u8, part: u8) -> JsonLines<'a> {
+        JsonLines {
+            output,
+            day,
+            part,
+            step: 0,
+            error: None,
+        }
+    }
+
+    pub fn finish(self) -> io::Result<usize> {
+        match self.error {
+            Some(error) => Err(error),
+            None => self.output.flush().map(|_| self.step),
+        }
+    }
+}
+
+impl Trace for JsonLines<'_> {
+    fn step(&mut self, state: impl FnOnce() -> Value) {
+        if self.error.is_some() {
+            return;
+        }
+        let result = writeln!(
+            self.output,
+            "{{\"day\":{},\"part\":{},\"step\":{},\"state\":{}}}",
+            self.day,
+            self.part,
+            self.step,
+            state()
+        );
+        self.step += 1;
+        self.error = result.err();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value_should_be_written_as_json() {
+        let value = Value::Object(vec![
+            ("name", Value::Text("a \"b\"\n".to_string())),
+            ("knots", Value::List(vec![Value::Number(-1), Value::Null])),
+            ("lit", Value::Bool(true)),
+        ]);
+
+        assert_eq!(
+            value.to_string(),
+            "{\"name\":\"a \\\"b\\\"\\n\",\"knots\":[-1,null],\"lit\":true}"
+        );
+    }
+
+    #[test]
+    fn json_lines_should_write_a_numbered_line_per_step() {
+        let mut output = Vec::new();
+        let mut trace = JsonLines::new(&mut output, 9, 2);
+        trace.step(|| Value::Number(1));
+        trace.step(|| Value::Number(2));
+
+        assert_eq!(trace.finish().unwrap(), 2);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "{\"day\":9,\"part\":2,\"step\":0,\"state\":1}\n{\"day\":9,\"part\":2,\"step\":1,\"state\":2}\n"
+        );
+    }
+}